@@ -0,0 +1,126 @@
+use aoc_common::{ParseError, Solution};
+
+// '(' goes up a floor and ')' goes down one
+fn solution1(steps: &[i32]) -> i32 {
+    steps.iter().sum()
+}
+
+// The 1-based position of the step that first enters the basement, or -1 if
+// Santa never gets there, which is what main.hs prints as well.
+fn solution2(steps: &[i32]) -> i64 {
+    let mut floor = 0;
+
+    for (i, &step) in steps.iter().enumerate() {
+        floor += step;
+        if floor == -1 {
+            return i as i64 + 1;
+        }
+    }
+
+    -1
+}
+
+/// Ignores trailing whitespace, like a Windows line ending, which main.hs
+/// counts as staying on the same floor.
+fn parse(contents: &str) -> Result<Vec<i32>, ParseError> {
+    let trimmed = contents.trim_end();
+
+    trimmed
+        .char_indices()
+        .map(|(i, c)| match c {
+            '(' => Ok(1),
+            ')' => Ok(-1),
+            _ => Err(ParseError::at(
+                contents,
+                &trimmed[i..i + c.len_utf8()],
+                format!("unexpected {c:?}, expected '(' or ')'"),
+            )),
+        })
+        .collect()
+}
+
+#[derive(Default)]
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i64;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, steps: &Self::Input<'_>) -> Self::Part1 {
+        solution1(steps)
+    }
+
+    fn part2(&self, steps: &Self::Input<'_>) -> Self::Part2 {
+        solution2(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example_part1() {
+        let examples = [
+            ("(())", 0),
+            ("()()", 0),
+            ("(((", 3),
+            ("(()(()(", 3),
+            ("))(((((", 3),
+            ("())", -1),
+            ("))(", -1),
+            (")))", -3),
+            (")())())", -3),
+        ];
+
+        for (input, floor) in examples {
+            assert_eq!(solution1(&parse(input).unwrap()), floor, "{input}");
+        }
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(solution2(&parse(")").unwrap()), 1);
+        assert_eq!(solution2(&parse("()())").unwrap()), 5);
+        assert_eq!(solution2(&parse("((").unwrap()), -1);
+    }
+
+    #[test]
+    fn ignores_trailing_whitespace() {
+        assert_eq!(parse("())\r\n \t\n"), parse("())"));
+        assert!(parse("(\n)").is_err());
+    }
+
+    /// main.hs, which every other character leaves on the same floor.
+    fn main_hs(input: &str) -> (i32, i64) {
+        let char_to_number = |c| match c {
+            '(' => 1,
+            ')' => -1,
+            _ => 0,
+        };
+
+        let solution1 = input.chars().map(char_to_number).sum();
+        let sums = std::iter::once(0).chain(input.chars().scan(0, |sum, c| {
+            *sum += char_to_number(c);
+            Some(*sum)
+        }));
+        let position = (1..).zip(sums).find(|&(_, sum)| sum == -1);
+        let solution2 = position.map_or(0, |(position, _)| position) - 1;
+
+        (solution1, solution2)
+    }
+
+    proptest! {
+        #[test]
+        fn matches_main_hs(input in "[()]{0,7000}[ \t\r\n]{0,3}") {
+            let steps = parse(&input).unwrap();
+            prop_assert_eq!((solution1(&steps), solution2(&steps)), main_hs(&input));
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Writes the dial's position after every instruction, or checks a saved
+//! trace by replaying it.
+//!
+//! ```sh
+//! cargo run -p aoc2025-day1 --bin day1-trace -- record inputs/2025/day1.txt > trace.csv
+//! cargo run -p aoc2025-day1 --bin day1-trace -- replay trace.csv
+//! ```
+
+use std::process::ExitCode;
+
+use aoc_common::{Options, Solution};
+use aoc2025_day1::{Day1, trace};
+
+const USAGE: &str = "\
+usage: day1-trace record <instructions> [--json] [-o <key>=<value>]...
+       day1-trace replay <trace> [-o <key>=<value>]...";
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (rest, options) = Options::split_args(args).map_err(|err| err.to_string())?;
+
+    let mut day = Day1::default();
+    day.configure(&options).map_err(|err| err.to_string())?;
+
+    let read = |path: &str| {
+        std::fs::read_to_string(path).map_err(|err| format!("could not read {path}: {err}"))
+    };
+
+    match rest.as_slice() {
+        ["record", path, format @ ..] => {
+            let contents = read(path)?;
+            let offsets = day
+                .parse(&contents)
+                .map_err(|err| err.diagnostic(&contents))?;
+            let steps = trace::trace(day.dial(), &offsets);
+
+            match format {
+                [] => print!("{}", trace::to_csv(&steps)),
+                ["--json"] => println!("{}", trace::to_json(&steps)),
+                _ => return Err(USAGE.to_owned()),
+            }
+        }
+        ["replay", path] => {
+            let contents = read(path)?;
+            let steps = trace::read(&contents).map_err(|err| err.diagnostic(&contents))?;
+            let total =
+                trace::replay(day.dial(), &steps).map_err(|mismatch| mismatch.to_string())?;
+            println!(
+                "Replayed {} instructions with {} zero hits",
+                steps.len(),
+                total
+            );
+        }
+        _ => return Err(USAGE.to_owned()),
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
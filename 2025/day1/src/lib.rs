@@ -0,0 +1,283 @@
+pub mod trace;
+
+use aoc_common::{OptionError, Options, ParseError, Solution, parse};
+
+/// A dial with `size` notches numbered from zero, pointing at `start` before
+/// the first rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    pub size: i64,
+    pub start: i64,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial {
+            size: 100,
+            start: 50,
+        }
+    }
+}
+
+impl Dial {
+    /// Turns the dial from `location` by `offset` notches, returning where it
+    /// ends up and how often it pointed at zero on the way, the end included.
+    ///
+    /// A rotation from `location` to `target` passes a multiple of the dial
+    /// size for every step of the floored division, so this is constant time
+    /// no matter how many notches it turns. Turning left is counted from one
+    /// notch further down, so that starting on zero is not a hit and ending on
+    /// it is.
+    pub fn rotate(&self, location: i64, offset: i64) -> (i64, i64) {
+        self.checked_rotate(location, offset)
+            .expect("the dial turns within the range of an i64")
+    }
+
+    /// Like [`Dial::rotate`], but `None` if the dial would turn past the range
+    /// of an `i64`, for offsets that don't come from a puzzle input.
+    pub fn checked_rotate(&self, location: i64, offset: i64) -> Option<(i64, i64)> {
+        let target = location.checked_add(offset)?;
+
+        let hits = if offset >= 0 {
+            target.div_euclid(self.size) - location.div_euclid(self.size)
+        } else {
+            (location - 1).div_euclid(self.size) - target.checked_sub(1)?.div_euclid(self.size)
+        };
+
+        Some((target.rem_euclid(self.size), hits))
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    let mut offsets = Vec::new();
+
+    for line in input.lines() {
+        let line = line.trim();
+        let Some(direction) = line.chars().next() else {
+            continue;
+        };
+
+        let sign = match direction {
+            'R' => 1,
+            'L' => -1,
+            char => {
+                return Err(ParseError::at(
+                    input,
+                    &line[..char.len_utf8()],
+                    format!("invalid direction {char:?}, expected 'L' or 'R'"),
+                ));
+            }
+        };
+
+        // The direction gives the sign, so the count itself has to be bare
+        // digits; `u32` alone would still let a `+` through.
+        let count = &line[1..];
+        if !count.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(ParseError::at(input, count, "expected a count of notches"));
+        }
+        offsets.push(sign * i64::from(parse::number::<u32>(input, count)?));
+    }
+
+    Ok(offsets)
+}
+
+/// Counts the rotations that leave the dial pointing at zero.
+fn solution1(dial: Dial, offsets: &[i64]) -> usize {
+    let mut location = dial.start;
+
+    offsets
+        .iter()
+        .filter(|&&offset| {
+            (location, _) = dial.rotate(location, offset);
+            location == 0
+        })
+        .count()
+}
+
+/// Counts how often the dial points at zero, during a rotation or at its end.
+fn solution2(dial: Dial, offsets: &[i64]) -> usize {
+    let mut occurences = 0;
+    let mut location = dial.start;
+
+    for &offset in offsets {
+        let hits;
+        (location, hits) = dial.rotate(location, offset);
+        occurences += hits;
+    }
+
+    occurences as usize
+}
+
+#[derive(Default)]
+pub struct Day1 {
+    dial: Dial,
+}
+
+impl Day1 {
+    /// The dial as configured by the options.
+    pub fn dial(&self) -> Dial {
+        self.dial
+    }
+}
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Takes the dial's `size` and `start` position for variant puzzles. A
+    /// resized dial starts halfway round, like the puzzle's 50 of 100.
+    fn configure(&mut self, options: &Options) -> Result<(), OptionError> {
+        options.only(&["size", "start"])?;
+
+        if let Some(size) = options.get::<i64>("size")? {
+            if size < 1 {
+                return Err(OptionError::invalid("size", size, "at least one notch"));
+            }
+            self.dial = Dial {
+                size,
+                start: size / 2,
+            };
+        }
+
+        self.dial.start = options.get("start")?.unwrap_or(self.dial.start);
+        if !(0..self.dial.size).contains(&self.dial.start) {
+            let expected = format!("a notch below the dial size of {}", self.dial.size);
+            return Err(OptionError::invalid("start", self.dial.start, expected));
+        }
+
+        Ok(())
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, offsets: &Self::Input<'_>) -> Self::Part1 {
+        solution1(self.dial, offsets)
+    }
+
+    fn part2(&self, offsets: &Self::Input<'_>) -> Self::Part2 {
+        solution2(self.dial, offsets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(solution1(Dial::default(), &parse(EXAMPLE).unwrap()), 3);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(solution2(Dial::default(), &parse(EXAMPLE).unwrap()), 6);
+    }
+
+    #[test]
+    fn other_dials() {
+        let dial = Dial { size: 10, start: 0 };
+        assert_eq!(solution1(dial, &[5, 5, -20, 3]), 2);
+        assert_eq!(solution2(dial, &[5, 5, -20, 3]), 3);
+    }
+
+    #[test]
+    fn huge_rotations() {
+        let dial = Dial::default();
+        assert_eq!(solution2(dial, &[1_000_000_000]), 10_000_000);
+        assert_eq!(solution2(dial, &[-1_000_000_000]), 10_000_000);
+        assert_eq!(solution2(dial, &[-50, 100, -100, 1]), 3);
+    }
+
+    /// The original implementation, turning the dial one notch at a time.
+    fn stepping(offsets: &[i64]) -> usize {
+        let mut occurences = 0;
+        let mut location = 50;
+
+        for &offset in offsets {
+            let op = offset.signum();
+            for _ in 0..offset.abs() {
+                location = (location + op).rem_euclid(100);
+                if location == 0 {
+                    occurences += 1;
+                }
+            }
+        }
+
+        occurences
+    }
+
+    proptest! {
+        #[test]
+        fn matches_stepping(offsets in prop::collection::vec(-1000_i64..1000, 0..50)) {
+            prop_assert_eq!(solution2(Dial::default(), &offsets), stepping(&offsets));
+        }
+    }
+
+    fn configured(options: &[&str]) -> Result<Dial, OptionError> {
+        let mut day = Day1::default();
+        let mut parsed = Options::new();
+        for option in options {
+            parsed.insert(option)?;
+        }
+        day.configure(&parsed)?;
+        Ok(day.dial())
+    }
+
+    #[test]
+    fn configures_the_dial() {
+        assert_eq!(configured(&[]), Ok(Dial::default()));
+        assert_eq!(configured(&["size=10"]), Ok(Dial { size: 10, start: 5 }));
+        assert_eq!(
+            configured(&["size=10", "start=0"]),
+            Ok(Dial { size: 10, start: 0 })
+        );
+        assert_eq!(
+            configured(&["start=99"]),
+            Ok(Dial {
+                size: 100,
+                start: 99
+            })
+        );
+
+        assert!(configured(&["size=0"]).is_err());
+        assert!(configured(&["size=10", "start=10"]).is_err());
+        assert!(configured(&["notches=10"]).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_direction() {
+        let error = parse("R10\nU5\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 1, "U"));
+    }
+
+    #[test]
+    fn rejects_signed_counts() {
+        for input in ["R-5\n", "L-2147483648\n", "L+5\n"] {
+            let error = parse(input).unwrap_err();
+            assert_eq!((error.line, error.column), (1, 2), "{input:?}");
+        }
+        assert_eq!(parse("L4294967295\n"), Ok(vec![-4_294_967_295]));
+    }
+
+    #[test]
+    fn skips_blank_lines() {
+        assert_eq!(parse("R10\n   \n  L5 \n\n"), Ok(vec![10, -5]));
+    }
+}
@@ -0,0 +1,222 @@
+//! A per-instruction record of the dial, for checking the zero counting by
+//! hand or against an earlier run.
+
+use std::fmt::{self, Display};
+
+use aoc_common::{ParseError, parse};
+use serde::{Deserialize, Serialize};
+
+use crate::Dial;
+
+/// Where the dial ended up after one instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Step {
+    /// The 1-based number of the instruction.
+    pub instruction: usize,
+    pub delta: i64,
+    pub position: i64,
+    /// How often the dial pointed at zero during this instruction.
+    pub zero_hits: i64,
+}
+
+const CSV_HEADER: &str = "instruction,delta,position,zero_hits";
+
+pub fn trace(dial: Dial, offsets: &[i64]) -> Vec<Step> {
+    let mut location = dial.start;
+
+    offsets
+        .iter()
+        .enumerate()
+        .map(|(i, &offset)| {
+            let zero_hits;
+            (location, zero_hits) = dial.rotate(location, offset);
+            Step {
+                instruction: i + 1,
+                delta: offset,
+                position: location,
+                zero_hits,
+            }
+        })
+        .collect()
+}
+
+pub fn to_csv(steps: &[Step]) -> String {
+    let mut csv = format!("{CSV_HEADER}\n");
+    for step in steps {
+        csv += &format!(
+            "{},{},{},{}\n",
+            step.instruction, step.delta, step.position, step.zero_hits
+        );
+    }
+    csv
+}
+
+pub fn to_json(steps: &[Step]) -> String {
+    serde_json::to_string_pretty(steps).expect("steps always serialize")
+}
+
+pub fn from_csv(input: &str) -> Result<Vec<Step>, ParseError> {
+    let mut lines = input.lines();
+
+    match lines.next() {
+        Some(CSV_HEADER) => {}
+        header => {
+            let header = header.unwrap_or(input);
+            return Err(ParseError::at(
+                input,
+                header,
+                format!("expected the header {CSV_HEADER:?}"),
+            ));
+        }
+    }
+
+    lines
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let fields = line.split(',').collect::<Vec<_>>();
+            let &[instruction, delta, position, zero_hits] = fields.as_slice() else {
+                return Err(ParseError::at(input, line, "expected 4 fields"));
+            };
+
+            Ok(Step {
+                instruction: parse::number(input, instruction)?,
+                delta: parse::number(input, delta)?,
+                position: parse::number(input, position)?,
+                zero_hits: parse::number(input, zero_hits)?,
+            })
+        })
+        .collect()
+}
+
+pub fn from_json(input: &str) -> Result<Vec<Step>, ParseError> {
+    serde_json::from_str(input).map_err(|err| {
+        let text = input
+            .lines()
+            .nth(err.line().saturating_sub(1))
+            .unwrap_or_default();
+        ParseError::new(err.line(), err.column(), text, err.to_string())
+    })
+}
+
+/// Reads a trace written by either [`to_csv`] or [`to_json`].
+pub fn read(input: &str) -> Result<Vec<Step>, ParseError> {
+    if input.trim_start().starts_with('[') {
+        from_json(input)
+    } else {
+        from_csv(input)
+    }
+}
+
+/// The first step of a replayed trace that disagrees with the recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub recorded: Step,
+    /// `None` if the recorded delta turns the dial too far to replay.
+    pub replayed: Option<Step>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let recorded = &self.recorded;
+        let Some(replayed) = &self.replayed else {
+            return write!(
+                f,
+                "instruction {} ({:+}) can not be replayed, it turns the dial too far",
+                recorded.instruction, recorded.delta,
+            );
+        };
+        write!(
+            f,
+            "instruction {} ({:+}) was recorded at position {} with {} zero hits, replaying it gives position {} with {}",
+            recorded.instruction,
+            recorded.delta,
+            recorded.position,
+            recorded.zero_hits,
+            replayed.position,
+            replayed.zero_hits,
+        )
+    }
+}
+
+/// Runs the deltas of a saved trace through the dial again and checks every
+/// step against the recording. Returns the total number of zero hits, which
+/// may outgrow an `i64` for a hand-written trace.
+pub fn replay(dial: Dial, recorded: &[Step]) -> Result<i128, Mismatch> {
+    let mut location = dial.start;
+    let mut total = 0;
+
+    for (i, &step) in recorded.iter().enumerate() {
+        let (position, zero_hits) = dial.checked_rotate(location, step.delta).ok_or(Mismatch {
+            recorded: step,
+            replayed: None,
+        })?;
+        let replayed = Step {
+            instruction: i + 1,
+            delta: step.delta,
+            position,
+            zero_hits,
+        };
+        if replayed != step {
+            return Err(Mismatch {
+                recorded: step,
+                replayed: Some(replayed),
+            });
+        }
+
+        location = position;
+        total += zero_hits as i128;
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let steps = trace(Dial::default(), &[-68, -30, 48, -5, 60]);
+        assert_eq!(
+            steps[1],
+            Step {
+                instruction: 2,
+                delta: -30,
+                position: 52,
+                zero_hits: 0
+            }
+        );
+
+        assert_eq!(from_csv(&to_csv(&steps)), Ok(steps.clone()));
+        assert_eq!(read(&to_json(&steps)), Ok(steps.clone()));
+        assert_eq!(replay(Dial::default(), &steps), Ok(3));
+    }
+
+    #[test]
+    fn replay_finds_the_first_mismatch() {
+        let mut steps = trace(Dial::default(), &[-68, -30, 48]);
+        steps[2].zero_hits = 0;
+
+        let mismatch = replay(Dial::default(), &steps).unwrap_err();
+        assert_eq!(
+            (
+                mismatch.recorded.instruction,
+                mismatch.replayed.unwrap().zero_hits
+            ),
+            (3, 1)
+        );
+    }
+
+    #[test]
+    fn replay_rejects_huge_deltas() {
+        let mut steps = trace(Dial::default(), &[-68, -30]);
+        steps[1].delta = i64::MAX;
+
+        let mismatch = replay(Dial::default(), &steps).unwrap_err();
+        assert_eq!(
+            (mismatch.recorded.instruction, mismatch.replayed),
+            (2, None)
+        );
+        assert!(mismatch.to_string().contains("turns the dial too far"));
+    }
+}
@@ -0,0 +1,293 @@
+use aoc_common::{ParseError, Solution, parse};
+use itertools::{Itertools, MinMaxResult};
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+
+#[derive(Eq, PartialEq, Hash, Clone)]
+struct NodeS1 {
+    state: Vec<bool>,
+    presses: usize,
+}
+
+fn find_min_button_presses_s1(pattern: &str, buttons: &[Vec<usize>]) -> usize {
+    let desired_state = vec![false; pattern.len()];
+
+    let mut pq = PriorityQueue::new();
+
+    pq.push(
+        NodeS1 {
+            state: pattern.chars().map(|c| c == '#').collect::<Vec<bool>>(),
+            presses: 0,
+        },
+        Reverse(0_usize),
+    );
+
+    while let Some((node, _)) = pq.pop() {
+
+        if node.state == desired_state {
+            return node.presses;
+        }
+
+        for button in buttons.iter() {
+            let mut new_state = node.state.clone();
+            for &index in button.iter() {
+                new_state[index] = !new_state[index];
+            }
+
+            pq.push(
+                NodeS1 {
+                    state: new_state,
+                    presses: node.presses + 1,
+                },
+                Reverse(node.presses + 1),
+            );
+        }
+    }
+
+    panic!("Unable to find solution")
+}
+
+fn solution1(machines: &[Machine]) -> usize {
+    machines
+        .iter()
+        // We can ignore the jolt requirements for this problem
+        .map(|machine| find_min_button_presses_s1(machine.pattern, &machine.buttons))
+        .sum()
+}
+
+fn apply_button_to_state(state: &[isize], button: &[usize]) -> Vec<isize> {
+    let mut new_state = state.to_vec();
+    for &v in button {
+        new_state[v] -= 1;
+    }
+    new_state
+}
+
+struct NodeS2<'a> {
+    state: Vec<isize>,
+    presses: usize,
+    available_buttons: &'a [Vec<usize>],
+}
+
+fn find_min_button_presses_s2(joltages: &[usize], buttons: &[Vec<usize>]) -> usize {
+    let mut best = usize::MAX;
+    let initial_state: Vec<isize> = joltages.iter().map(|&x| x as isize).collect();
+
+    let mut stack = vec![];
+
+    stack.push(NodeS2 {
+        state: initial_state,
+        presses: 0,
+        available_buttons: buttons,
+    });
+
+    'search: while let Some(NodeS2 {
+        state,
+        presses,
+        available_buttons,
+    }) = stack.pop()
+    {
+        let MinMaxResult::MinMax(&min, &max) = state.iter().minmax() else {
+            panic!();
+        };
+
+        // We have gone over, end of the line
+        if min < 0 {
+            continue;
+        }
+
+        // Done, All joltages are zero
+        if max == 0 {
+            best = (best).min(presses);
+            continue;
+        }
+
+        // We cannot beat the current best solution. So why even try
+        if presses + (max as usize) >= best {
+            continue;
+        }
+
+        // This optimization tries to focus on buttons that decrease high joltages while leaving low joltages alone
+        // Its the only reason this thing runs in any reasonable amount of time
+        for (i, j) in (0..state.len()).tuple_combinations::<(_, _)>() {
+            if state[i] == state[j] {
+                continue;
+            }
+
+            let (i, j) = if state[i] > state[j] { (i, j) } else { (j, i) };
+
+            let helpful_buttons: Vec<usize> = available_buttons
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, b)| {
+                    if b.contains(&i) && !b.contains(&j) {
+                        Some(idx)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            match helpful_buttons[..] {
+                // No button will help us reach our goal. This means we are already
+                // in a dead end since all possibilities from here on out
+                // decrease both i and j together
+                [] => continue 'search,
+                // There is only one button that will help reach the goal
+                // we MUST press it and can ignore everything else (for now)
+                [button] => {
+                    stack.push(NodeS2 {
+                        state: apply_button_to_state(&state, &available_buttons[button]),
+                        presses: presses + 1,
+                        available_buttons,
+                    });
+                    continue 'search;
+                }
+                _ => {}
+            }
+        }
+
+        for (i, button) in available_buttons.iter().enumerate().rev() {
+            stack.push(NodeS2 {
+                state: apply_button_to_state(&state, button),
+                presses: presses + 1,
+                available_buttons: &available_buttons[i..],
+            });
+        }
+    }
+
+    assert!(best != usize::MAX);
+
+    best
+}
+
+fn solution2(machines: &[Machine]) -> usize {
+    machines
+        .iter()
+        // We can ignore the light pattern for this problem
+        .map(|machine| find_min_button_presses_s2(&machine.joltages, &machine.buttons))
+        .sum()
+}
+
+pub struct Machine<'a> {
+    pattern: &'a str,
+    buttons: Vec<Vec<usize>>,
+    joltages: Vec<usize>,
+}
+
+/// Parses a comma separated list of numbers wrapped in `open` and `close`,
+/// such as `(1,3)` or `{3,5,4,7}`.
+fn parse_list(input: &str, token: &str, open: char, close: char) -> Result<Vec<usize>, ParseError> {
+    let list = token
+        .strip_prefix(open)
+        .and_then(|token| token.strip_suffix(close))
+        .ok_or_else(|| {
+            ParseError::at(
+                input,
+                token,
+                format!("expected a list wrapped in {open}{close}"),
+            )
+        })?;
+
+    list.split(',').map(|n| parse::number(input, n)).collect()
+}
+
+impl<'a> Machine<'a> {
+    /// Parses a `[.##.] (3) (1,3) {3,5,4,7}` line, which has to be a slice of `input`.
+    fn parse(input: &str, line: &'a str) -> Result<Self, ParseError> {
+        let mut tokens: Vec<&str> = line.split(' ').collect();
+
+        let pattern = tokens[0]
+            .strip_prefix('[')
+            .and_then(|token| token.strip_suffix(']'))
+            .filter(|pattern| pattern.chars().all(|c| c == '.' || c == '#'))
+            .ok_or_else(|| {
+                ParseError::at(input, tokens[0], "expected a light pattern like [.##.]")
+            })?;
+
+        let Some(joltages) = tokens.pop().filter(|_| tokens.len() > 1) else {
+            return Err(ParseError::at(
+                input,
+                line,
+                "expected a light pattern, buttons and joltages",
+            ));
+        };
+        let joltages = parse_list(input, joltages, '{', '}')?;
+        if joltages.len() != pattern.len() {
+            return Err(ParseError::at(
+                input,
+                line,
+                "expected a joltage for every light",
+            ));
+        }
+
+        let mut buttons = Vec::new();
+        for token in &tokens[1..] {
+            let button = parse_list(input, token, '(', ')')?;
+            if button.iter().any(|&light| light >= pattern.len()) {
+                return Err(ParseError::at(
+                    input,
+                    token,
+                    "button toggles a light that does not exist",
+                ));
+            }
+            buttons.push(button);
+        }
+
+        Ok(Machine {
+            pattern,
+            buttons,
+            joltages,
+        })
+    }
+}
+
+fn parse(contents: &str) -> Result<Vec<Machine<'_>>, ParseError> {
+    contents
+        .trim_end_matches('\n')
+        .split('\n')
+        .map(|line| Machine::parse(contents, line))
+        .collect()
+}
+
+#[derive(Default)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Machine<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, machines: &Self::Input<'_>) -> Self::Part1 {
+        solution1(machines)
+    }
+
+    fn part2(&self, machines: &Self::Input<'_>) -> Self::Part2 {
+        solution2(machines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
+";
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(solution1(&parse(EXAMPLE).unwrap()), 7);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(solution2(&parse(EXAMPLE).unwrap()), 33);
+    }
+}
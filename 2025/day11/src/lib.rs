@@ -0,0 +1,169 @@
+use aoc_common::{ParseError, Solution};
+use std::{
+    collections::{HashMap, HashSet},
+    vec,
+};
+
+/// A device and the devices its outputs are connected to.
+type Device<'a> = (&'a str, Vec<&'a str>);
+
+fn solution1(devices: &[Device]) -> usize {
+    let mut outs = HashSet::new();
+    let mut map = HashMap::new();
+
+    let mut starts = vec![];
+
+    for (a, outputs) in devices.iter() {
+        let (a, mut outputs) = (a.to_string(), outputs.clone());
+
+        if a == "you" {
+            starts.append(&mut outputs);
+            continue;
+        }
+
+        if outputs[..] == ["out"] {
+            outs.insert(a.clone());
+            continue;
+        }
+
+        map.insert(a, outputs);
+    }
+
+    let mut paths = starts.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    let mut total = 0_usize;
+
+    while let Some(path) = paths.pop() {
+        let next = map.get(&path).unwrap();
+
+        for p in next.iter() {
+            if outs.contains(*p) {
+                total += 1;
+            } else {
+                paths.push(p.to_string());
+            }
+        }
+    }
+
+    total
+}
+
+fn solution2(devices: &[Device]) -> usize {
+    let map: HashMap<&str, Vec<&str>> = devices.iter().cloned().collect();
+
+    fn dfs<'a>(
+        node: &'a str,
+        map: &'a HashMap<&'a str, Vec<&'a str>>,
+        memo: &'_ mut HashMap<(&'a str, bool, bool), usize>,
+        state: (bool, bool),
+    ) -> usize {
+        let (is_fft, is_dac) = (state.0 || node == "fft", state.1 || node == "dac");
+
+        if node == "out" {
+            return (is_fft && is_dac) as usize;
+        }
+
+        if let Some(paths) = memo.get(&(node, is_fft, is_dac)) {
+            return *paths;
+        }
+
+        let mut total = 0_usize;
+
+        for neighbor in map
+            .get(node)
+            .unwrap_or_else(|| panic!("Node {} not found in map", node))
+            .iter()
+        {
+            total += dfs(neighbor, map, memo, (is_fft, is_dac));
+        }
+
+        memo.insert((node, is_fft, is_dac), total);
+        total
+    }
+
+    let mut memo = HashMap::new();
+
+    dfs("svr", &map, &mut memo, (false, false))
+}
+
+fn parse(contents: &str) -> Result<Vec<Device<'_>>, ParseError> {
+    contents
+        .trim_end_matches('\n')
+        .split('\n')
+        .map(|line| {
+            let (a, b) = line.split_once(":").ok_or_else(|| {
+                ParseError::at(contents, line, "expected a device as <name>: <outputs>")
+            })?;
+            let outputs = b.split_whitespace().collect::<Vec<_>>();
+            if outputs.is_empty() {
+                return Err(ParseError::at(contents, line, "device has no outputs"));
+            }
+
+            Ok((a.trim(), outputs))
+        })
+        .collect()
+}
+
+#[derive(Default)]
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Device<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, devices: &Self::Input<'_>) -> Self::Part1 {
+        solution1(devices)
+    }
+
+    fn part2(&self, devices: &Self::Input<'_>) -> Self::Part2 {
+        solution2(devices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_PART1: &str = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+";
+    const EXAMPLE_PART2: &str = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(solution1(&parse(EXAMPLE_PART1).unwrap()), 5);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(solution2(&parse(EXAMPLE_PART2).unwrap()), 2);
+    }
+}
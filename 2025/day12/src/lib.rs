@@ -0,0 +1,378 @@
+use std::collections::HashSet;
+
+use aoc_common::{Grid, ParseError, Solution, Unsolved, parse};
+
+pub struct Shape {
+    shape: Grid<bool>,
+    surface: usize,
+}
+
+impl Shape {
+    /// The distinct ways to lay the shape down, flipped and rotated. Each is
+    /// given as the offsets of its cells from its first cell in reading order,
+    /// so every other cell comes after that one.
+    fn orientations(&self) -> Vec<Vec<(isize, isize)>> {
+        let mut orientations = Vec::new();
+
+        for flip in [ShapeFlip::None, ShapeFlip::Flipped] {
+            for rotation in [
+                ShapeRotation::Deg0,
+                ShapeRotation::Deg90,
+                ShapeRotation::Deg180,
+                ShapeRotation::Deg270,
+            ] {
+                let grid = ShapePermutation(flip, rotation, self).get_permuted_grid();
+                let cells: Vec<_> = grid
+                    .iter()
+                    .filter(|&(_, &filled)| filled)
+                    .map(|((x, y), _)| (x as isize, y as isize))
+                    .collect();
+
+                let (first_x, first_y) = cells[0];
+                let offsets: Vec<_> = cells
+                    .iter()
+                    .map(|&(x, y)| (x - first_x, y - first_y))
+                    .collect();
+                if !orientations.contains(&offsets) {
+                    orientations.push(offsets);
+                }
+            }
+        }
+
+        orientations
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ShapeFlip {
+    None,
+    Flipped,
+}
+
+#[derive(Clone, Copy)]
+enum ShapeRotation {
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+struct ShapePermutation<'a>(ShapeFlip, ShapeRotation, &'a Shape);
+
+impl<'a> ShapePermutation<'a> {
+    fn get_permuted_grid(&self) -> Grid<bool> {
+        let grid = match self.0 {
+            ShapeFlip::None => self.2.shape.clone(),
+            ShapeFlip::Flipped => self.2.shape.flipped(),
+        };
+
+        match self.1 {
+            ShapeRotation::Deg0 => grid,
+            ShapeRotation::Deg90 => grid.rotated(),
+            ShapeRotation::Deg180 => grid.rotated().rotated(),
+            ShapeRotation::Deg270 => grid.rotated().rotated().rotated(),
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn apply_shape_permutation(
+    grid: &mut Grid<bool>,
+    shape_perm: &ShapePermutation,
+    top_left: (usize, usize),
+) {
+    let permuted_grid = shape_perm.get_permuted_grid();
+
+    for ((x, y), &filled) in permuted_grid.iter() {
+        if filled {
+            grid[(top_left.0 + x, top_left.1 + y)] = true;
+        }
+    }
+}
+
+/// Parses a shape block, which has to be a slice of `input`.
+fn parse_shape(input: &str, data: &str) -> Result<Shape, ParseError> {
+    let (_, data) = data.split_once("\n").ok_or_else(|| {
+        ParseError::at(input, data, "expected a shape index followed by the shape")
+    })?;
+
+    let shape = Grid::parse_within(input, data, |c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(format!("unexpected {c:?}, expected '#' or '.'")),
+    })?;
+
+    if (shape.width(), shape.height()) != (3, 3) {
+        return Err(ParseError::at(
+            input,
+            data,
+            "expected a shape of 3 by 3 cells",
+        ));
+    }
+
+    let surface = shape.iter().filter(|&(_, &b)| b).count();
+
+    Ok(Shape { shape, surface })
+}
+
+pub struct Region {
+    width: usize,
+    height: usize,
+    presents: Vec<usize>,
+}
+
+/// Parses a `12x5: 1 0 1 0 2 2` line, which has to be a slice of `input`.
+fn parse_region(input: &str, line: &str, shapes: usize) -> Result<Region, ParseError> {
+    let (size, shapes_required) = line.split_once(": ").ok_or_else(|| {
+        ParseError::at(
+            input,
+            line,
+            "expected a region as <width>x<height>: <presents>",
+        )
+    })?;
+
+    let (width, height) = size
+        .split_once('x')
+        .ok_or_else(|| ParseError::at(input, size, "expected a size as <width>x<height>"))?;
+
+    let presents = shapes_required
+        .split(' ')
+        .map(|shape| parse::number(input, shape))
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    if presents.len() != shapes {
+        return Err(ParseError::at(
+            input,
+            shapes_required,
+            format!("expected a count for each of the {shapes} shapes"),
+        ));
+    }
+
+    Ok(Region {
+        width: parse::number(input, width)?,
+        height: parse::number(input, height)?,
+        presents,
+    })
+}
+
+/// Whether all the presents fit into the region without overlapping.
+///
+/// The real inputs are decided by area alone: either every present gets a
+/// 3x3 square of its own or their cells don't even fit. Only regions in
+/// between, like the example's, need an actual packing search.
+fn fits(shapes: &[Shape], region: &Region) -> bool {
+    let presents = region.presents.iter().sum::<usize>();
+    if (region.width / 3) * (region.height / 3) >= presents {
+        return true;
+    }
+
+    let area = region.width * region.height;
+    let surface = shapes
+        .iter()
+        .zip(&region.presents)
+        .map(|(shape, &count)| shape.surface * count)
+        .sum::<usize>();
+    if surface > area {
+        return false;
+    }
+
+    // Presents can be turned, so the region can be too. Searching along its
+    // shorter side keeps the rows that make up a search state short.
+    let (width, height) = if region.width <= region.height {
+        (region.width, region.height)
+    } else {
+        (region.height, region.width)
+    };
+    let mut packing = Packing {
+        grid: Grid::filled(width, height, false),
+        orientations: shapes.iter().map(Shape::orientations).collect(),
+        dead_ends: HashSet::new(),
+    };
+    packing.pack(0, &mut region.presents.clone(), area - surface)
+}
+
+/// Everything the rest of a packing search depends on once the cells before
+/// an index are decided: that index, the next two rows and a cell, which are
+/// all a present placed earlier can reach into, the presents left to place
+/// and the cells that can still stay empty.
+type State = (usize, Vec<bool>, Vec<usize>, usize);
+
+/// A search for a packing of presents, filling the grid in reading order.
+struct Packing {
+    grid: Grid<bool>,
+    orientations: Vec<Vec<Vec<(isize, isize)>>>,
+    dead_ends: HashSet<State>,
+}
+
+impl Packing {
+    fn state(&self, index: usize, counts: &[usize], spare: usize) -> State {
+        let width = self.grid.width();
+        let end = (index + 2 * width + 3).min(width * self.grid.height());
+        let window = (index..end)
+            .map(|i| self.grid[(i % width, i / width)])
+            .collect();
+        (index, window, counts.to_vec(), spare)
+    }
+
+    /// Decides the free cells in reading order, starting at index `from`:
+    /// each one either becomes the first cell of a present or stays empty,
+    /// which only `spare` cells can afford to.
+    fn pack(&mut self, from: usize, counts: &mut [usize], spare: usize) -> bool {
+        if counts.iter().all(|&count| count == 0) {
+            return true;
+        }
+
+        let width = self.grid.width();
+        let cells = width * self.grid.height();
+        let Some(index) = (from..cells).find(|&i| !self.grid[(i % width, i / width)]) else {
+            return false;
+        };
+
+        let state = self.state(index, counts, spare);
+        if self.dead_ends.contains(&state) {
+            return false;
+        }
+        let packed = self.place(index, counts, spare)
+            || spare > 0 && self.pack(index + 1, counts, spare - 1);
+        if !packed {
+            self.dead_ends.insert(state);
+        }
+        packed
+    }
+
+    /// Tries every orientation of every present left with its first cell on
+    /// the free cell at `index`.
+    fn place(&mut self, index: usize, counts: &mut [usize], spare: usize) -> bool {
+        let width = self.grid.width();
+        let (x, y) = ((index % width) as isize, (index / width) as isize);
+
+        for shape in 0..counts.len() {
+            if counts[shape] == 0 {
+                continue;
+            }
+
+            for orientation in 0..self.orientations[shape].len() {
+                let cells: Option<Vec<_>> = self.orientations[shape][orientation]
+                    .iter()
+                    .map(|&(dx, dy)| {
+                        let (nx, ny) = (x + dx, y + dy);
+                        (self.grid.contains(nx, ny) && !self.grid[(nx as usize, ny as usize)])
+                            .then_some((nx as usize, ny as usize))
+                    })
+                    .collect();
+                let Some(cells) = cells else {
+                    continue;
+                };
+
+                for &cell in &cells {
+                    self.grid[cell] = true;
+                }
+                counts[shape] -= 1;
+                let packed = self.pack(index + 1, counts, spare);
+                counts[shape] += 1;
+                for &cell in &cells {
+                    self.grid[cell] = false;
+                }
+
+                if packed {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+fn solution1(shapes: &[Shape], regions: &[Region]) -> usize {
+    regions.iter().filter(|region| fits(shapes, region)).count()
+}
+
+fn parse(contents: &str) -> Result<(Vec<Shape>, Vec<Region>), ParseError> {
+    let mut blocks = contents
+        .trim_end_matches('\n')
+        .split("\n\n")
+        .collect::<Vec<_>>();
+
+    let regions = blocks.pop().unwrap();
+
+    let shapes = blocks
+        .iter()
+        .map(|&data| parse_shape(contents, data))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let regions = regions
+        .split('\n')
+        .map(|line| parse_region(contents, line.trim(), shapes.len()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((shapes, regions))
+}
+
+#[derive(Default)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = (Vec<Shape>, Vec<Region>);
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        solution1(&input.0, &input.1)
+    }
+
+    fn part2(&self, _input: &Self::Input<'_>) -> Self::Part2 {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0:
+###
+##.
+##.
+
+1:
+###
+##.
+.##
+
+2:
+.##
+###
+##.
+
+3:
+##.
+###
+##.
+
+4:
+###
+#..
+###
+
+5:
+###
+.#.
+###
+
+4x4: 0 0 0 0 2 0
+12x5: 1 0 1 0 2 2
+12x5: 1 0 1 0 3 2
+";
+
+    #[test]
+    fn example_part1() {
+        let (shapes, regions) = parse(EXAMPLE).unwrap();
+        assert_eq!(solution1(&shapes, &regions), 2);
+    }
+}
@@ -0,0 +1,641 @@
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{IntervalSet, OptionError, Options, ParseError, Solution};
+use serde::Serialize;
+
+/// How often a block has to be repeated for an id to be invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repeats {
+    Exactly(u32),
+    AtLeast(u32),
+}
+
+impl Repeats {
+    fn allows(self, repeats: u32) -> bool {
+        match self {
+            Repeats::Exactly(k) => repeats == k,
+            Repeats::AtLeast(k) => repeats >= k,
+        }
+    }
+}
+
+/// Which ids are invalid: those written in `radix` as a block of digits
+/// repeated according to `repeats`, optionally only for some block lengths.
+///
+/// Repeats are counted the way the puzzle reads an id, so 1111 is both `11`
+/// twice and `1` four times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Policy {
+    pub repeats: Repeats,
+    /// The allowed numbers of digits in a block, any length if `None`.
+    pub blocks: Option<Vec<u32>>,
+    pub radix: u32,
+}
+
+impl Policy {
+    /// Part 1, an id made of two equal halves.
+    pub fn halves() -> Self {
+        Policy {
+            repeats: Repeats::Exactly(2),
+            blocks: None,
+            radix: 10,
+        }
+    }
+
+    /// Part 2, an id made of any block repeated at least twice.
+    pub fn repeated() -> Self {
+        Policy {
+            repeats: Repeats::AtLeast(2),
+            blocks: None,
+            radix: 10,
+        }
+    }
+
+    /// The most digits a `u64` can have in this radix.
+    fn max_digits(&self) -> u32 {
+        u64::MAX.ilog(self.radix as u64) + 1
+    }
+
+    /// Whether an id of `digits` digits whose shortest repeating block is
+    /// `period` digits long can be read as a repeat this policy allows.
+    fn accepts(&self, digits: u32, period: u32) -> bool {
+        (2..=digits)
+            .filter(|&repeats| digits.is_multiple_of(repeats) && self.repeats.allows(repeats))
+            .map(|repeats| digits / repeats)
+            .filter(|block| {
+                self.blocks
+                    .as_ref()
+                    .is_none_or(|blocks| blocks.contains(block))
+            })
+            .any(|block| block.is_multiple_of(period))
+    }
+
+    /// `1 + radix^block + radix^(2 block) + ...` with `repeats` terms,
+    /// multiplying a block of digits by it writes the block out `repeats`
+    /// times, e.g. 1001 for two repeats of a three digit block or 10101 for
+    /// three of a two digit one.
+    fn repeater(&self, block: u32, repeats: u32) -> u128 {
+        (0..repeats)
+            .map(|i| (self.radix as u128).pow(block * i))
+            .sum()
+    }
+
+    /// Sums the ids in `range` that consist of a `block` digit number written
+    /// out `repeats` times.
+    ///
+    /// Those ids are exactly the multiples of the repeater by a number with
+    /// `block` digits, so this is an arithmetic series and never looks at the
+    /// individual ids.
+    fn sum_repeated(&self, range: &RangeInclusive<u64>, block: u32, repeats: u32) -> i128 {
+        let repeater = self.repeater(block, repeats);
+        let (start, end) = (*range.start() as u128, *range.end() as u128);
+        let radix = self.radix as u128;
+
+        let first = start.div_ceil(repeater).max(radix.pow(block - 1));
+        let last = (end / repeater).min(radix.pow(block) - 1);
+        if first > last {
+            return 0;
+        }
+
+        ((first + last) * (last - first + 1) / 2 * repeater) as i128
+    }
+
+    /// Sums the invalid ids in `range`.
+    ///
+    /// Every id of `n` digits has a shortest repeating block whose length
+    /// `p` divides `n`, and whether the id is invalid only depends on `p`.
+    /// The ids repeating a block of `d` digits are those whose `p` divides
+    /// `d`, so Möbius inversion over the divisors of `p` sums the ids whose
+    /// shortest block is exactly `p` digits long, each of them exactly once.
+    fn sum(&self, range: &RangeInclusive<u64>) -> u128 {
+        let mut total = 0_i128;
+
+        for digits in 1..=self.max_digits() {
+            for period in
+                (1..digits).filter(|&p| digits.is_multiple_of(p) && self.accepts(digits, p))
+            {
+                for block in (1..=period).filter(|&d| period.is_multiple_of(d)) {
+                    total +=
+                        mobius(period / block) * self.sum_repeated(range, block, digits / block);
+                }
+            }
+        }
+
+        total as u128
+    }
+
+    /// The block lengths this policy allows for ids of `digits` digits, from
+    /// short to long.
+    fn blocks(&self, digits: u32) -> impl Iterator<Item = u32> + '_ {
+        (1..digits)
+            .filter(move |&block| {
+                digits.is_multiple_of(block) && self.repeats.allows(digits / block)
+            })
+            .filter(|block| {
+                self.blocks
+                    .as_ref()
+                    .is_none_or(|blocks| blocks.contains(block))
+            })
+    }
+
+    /// The length of the shortest block that repeats to `id`.
+    fn period(&self, id: u64, digits: u32) -> u32 {
+        let radix = self.radix as u128;
+        (1..=digits)
+            .filter(|&p| digits.is_multiple_of(p))
+            .find(|&p| {
+                id as u128 / radix.pow(digits - p) * self.repeater(p, digits / p) == id as u128
+            })
+            .unwrap_or(digits)
+    }
+
+    /// Lists the invalid ids in `range`, each of them once under the shortest
+    /// block the policy allows for it.
+    ///
+    /// Unlike [`Policy::sum`] this looks at every single id, it is only meant
+    /// for inspecting the matches.
+    pub fn invalid_ids(&self, range: &RangeInclusive<u64>) -> Vec<Match> {
+        let (start, end) = (*range.start() as u128, *range.end() as u128);
+        let radix = self.radix as u128;
+        let mut matches = Vec::new();
+
+        for digits in 1..=self.max_digits() {
+            for block in self.blocks(digits) {
+                let repeats = digits / block;
+                let repeater = self.repeater(block, repeats);
+                let first = start.div_ceil(repeater).max(radix.pow(block - 1));
+                let last = (end / repeater).min(radix.pow(block) - 1);
+
+                for value in first..=last {
+                    let id = (value * repeater) as u64;
+                    let period = self.period(id, digits);
+                    if self.blocks(digits).find(|b| b.is_multiple_of(period)) == Some(block) {
+                        matches.push(Match {
+                            id,
+                            block: value as u64,
+                            block_len: block,
+                            repeats,
+                        });
+                    }
+                }
+            }
+        }
+
+        matches.sort_by_key(|m| m.id);
+        matches
+    }
+}
+
+/// An invalid id and the block it repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub id: u64,
+    pub block: u64,
+    pub block_len: u32,
+    pub repeats: u32,
+}
+
+/// The Möbius function of small `n`: zero if a square divides `n`, otherwise
+/// -1 or 1 for an odd or even number of prime factors.
+fn mobius(mut n: u32) -> i128 {
+    let mut sign = 1;
+    let mut p = 2;
+
+    while n > 1 {
+        if n.is_multiple_of(p) {
+            n /= p;
+            if n.is_multiple_of(p) {
+                return 0;
+            }
+            sign = -sign;
+        }
+        p += 1;
+    }
+
+    sign
+}
+
+/// Writes `value` in `radix`, the way the ids appear in the input.
+fn format_radix(mut value: u64, radix: u32) -> String {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(char::from_digit((value % radix as u64) as u32, radix).unwrap());
+        value /= radix as u64;
+        if value == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+#[derive(Serialize)]
+struct RangeListing {
+    range: String,
+    matches: Vec<MatchListing>,
+}
+
+#[derive(Serialize)]
+struct MatchListing {
+    id: String,
+    block: String,
+    repeats: u32,
+}
+
+/// `matches.json` becomes `matches.part1.json` for part 1.
+fn part_path(path: &Path, part: usize) -> PathBuf {
+    match path.extension() {
+        Some(extension) => {
+            path.with_extension(format!("part{part}.{}", extension.to_string_lossy()))
+        }
+        None => path.with_extension(format!("part{part}")),
+    }
+}
+
+fn solution(policy: &Policy, ranges: &IntervalSet<u64>) -> u128 {
+    ranges.iter().map(|range| policy.sum(range)).sum()
+}
+
+/// The id ranges of the input.
+pub struct Ranges {
+    /// As they are listed, for reporting on them one by one.
+    listed: Vec<RangeInclusive<u64>>,
+    /// Merged, so that ids in overlapping ranges only count once.
+    merged: IntervalSet<u64>,
+}
+
+fn parse(contents: &str, radix: u32) -> Result<Ranges, ParseError> {
+    let number = |span: &str| {
+        u64::from_str_radix(span, radix)
+            .map_err(|_| ParseError::at(contents, span, "invalid number"))
+    };
+
+    contents
+        .trim()
+        .split(',')
+        .map(|range| {
+            let (start, end) = range.split_once('-').ok_or_else(|| {
+                ParseError::at(contents, range, "invalid range, expected <start>-<end>")
+            })?;
+
+            Ok(number(start)?..=number(end)?)
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|listed| Ranges {
+            merged: listed.iter().cloned().collect(),
+            listed,
+        })
+}
+
+pub struct Day2 {
+    part1: Policy,
+    part2: Policy,
+    /// 1 lists the number of invalid ids per range, 2 every single one.
+    verbosity: u8,
+    /// Where to dump the invalid ids of every range as JSON.
+    matches: Option<PathBuf>,
+}
+
+impl Default for Day2 {
+    fn default() -> Self {
+        Day2 {
+            part1: Policy::halves(),
+            part2: Policy::repeated(),
+            verbosity: 0,
+            matches: None,
+        }
+    }
+}
+
+impl Day2 {
+    /// Lists the invalid ids a part found in every range of the input, as far
+    /// as the options ask for it. Quiet by default, the listing goes to stderr
+    /// to keep the answers on stdout easy to pick out. Ranges are listed as
+    /// given, so an id in two overlapping ranges shows up under both.
+    fn list_matches(&self, part: usize, policy: &Policy, ranges: &[RangeInclusive<u64>]) {
+        if self.verbosity == 0 && self.matches.is_none() {
+            return;
+        }
+
+        let radix = policy.radix;
+        let mut listings = Vec::new();
+
+        for range in ranges {
+            let matches = policy.invalid_ids(range);
+            let range = format!(
+                "{}-{}",
+                format_radix(*range.start(), radix),
+                format_radix(*range.end(), radix)
+            );
+
+            if self.verbosity >= 1 {
+                eprintln!("part {part}, range {range}: {} invalid ids", matches.len());
+            }
+            if self.verbosity >= 2 {
+                for m in &matches {
+                    let (id, block) = (format_radix(m.id, radix), format_radix(m.block, radix));
+                    eprintln!("  {id} is {block} repeated {} times", m.repeats);
+                }
+            }
+
+            let matches = matches
+                .iter()
+                .map(|m| MatchListing {
+                    id: format_radix(m.id, radix),
+                    block: format_radix(m.block, radix),
+                    repeats: m.repeats,
+                })
+                .collect();
+            listings.push(RangeListing { range, matches });
+        }
+
+        if let Some(path) = &self.matches {
+            let path = part_path(path, part);
+            let json = serde_json::to_string_pretty(&listings).expect("listings always serialize");
+            if let Err(err) = std::fs::write(&path, json) {
+                eprintln!("could not write {}: {}", path.display(), err);
+            }
+        }
+    }
+}
+
+impl Solution for Day2 {
+    type Input<'a> = Ranges;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    /// Takes the `radix` of the ids and the allowed `blocks` lengths, e.g.
+    /// `1,3`, for both parts, and the `part1` and `part2` repeat counts as
+    /// `3` for exactly three repeats or `3+` for at least three. The invalid
+    /// ids are listed with `verbose=1` or `2`, and dumped as JSON with
+    /// `matches=<file>`, where `matches.json` becomes `matches.part1.json`
+    /// and `matches.part2.json`.
+    fn configure(&mut self, options: &Options) -> Result<(), OptionError> {
+        options.only(&["radix", "blocks", "part1", "part2", "verbose", "matches"])?;
+
+        self.verbosity = options.get("verbose")?.unwrap_or(self.verbosity);
+        self.matches = options.get("matches")?.or(self.matches.take());
+
+        for (key, policy) in [("part1", &mut self.part1), ("part2", &mut self.part2)] {
+            let Some(value) = options.get::<String>(key)? else {
+                continue;
+            };
+
+            let expected = "a repeat count of at least 2, like 2 or 2+";
+            policy.repeats = match value.strip_suffix('+') {
+                Some(k) => Repeats::AtLeast(
+                    k.parse()
+                        .map_err(|_| OptionError::invalid(key, &value, expected))?,
+                ),
+                None => Repeats::Exactly(
+                    value
+                        .parse()
+                        .map_err(|_| OptionError::invalid(key, &value, expected))?,
+                ),
+            };
+            if let Repeats::Exactly(k) | Repeats::AtLeast(k) = policy.repeats
+                && k < 2
+            {
+                return Err(OptionError::invalid(key, &value, expected));
+            }
+        }
+
+        if let Some(value) = options.get::<String>("blocks")? {
+            let blocks = value
+                .split(',')
+                .map(|block| block.parse().ok().filter(|&block| block > 0))
+                .collect::<Option<Vec<u32>>>()
+                .ok_or_else(|| {
+                    OptionError::invalid("blocks", &value, "a list of block lengths, like 1,3")
+                })?;
+            self.part1.blocks = Some(blocks.clone());
+            self.part2.blocks = Some(blocks);
+        }
+
+        if let Some(radix) = options.get::<u32>("radix")? {
+            if !(2..=36).contains(&radix) {
+                return Err(OptionError::invalid("radix", radix, "a radix from 2 to 36"));
+            }
+            self.part1.radix = radix;
+            self.part2.radix = radix;
+        }
+
+        Ok(())
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input, self.part1.radix)
+    }
+
+    fn part1(&self, ranges: &Self::Input<'_>) -> Self::Part1 {
+        solution(&self.part1, &ranges.merged)
+    }
+
+    fn part2(&self, ranges: &Self::Input<'_>) -> Self::Part2 {
+        solution(&self.part2, &ranges.merged)
+    }
+
+    fn report(&self, ranges: &Self::Input<'_>) {
+        self.list_matches(1, &self.part1, &ranges.listed);
+        self.list_matches(2, &self.part2, &ranges.listed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
+";
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(
+            solution(&Policy::halves(), &parse(EXAMPLE, 10).unwrap().merged),
+            1227775554
+        );
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(
+            solution(&Policy::repeated(), &parse(EXAMPLE, 10).unwrap().merged),
+            4174379265
+        );
+    }
+
+    /// Checks every id by looking at its digits, like the first version did.
+    fn scan(policy: &Policy, ranges: &IntervalSet<u64>) -> u128 {
+        let is_invalid = |&id: &u64| {
+            let mut digits = Vec::new();
+            let mut rest = id;
+            while rest > 0 || digits.is_empty() {
+                digits.push(rest % policy.radix as u64);
+                rest /= policy.radix as u64;
+            }
+
+            let len = digits.len() as u32;
+            (2..=len)
+                .filter(|&repeats| len.is_multiple_of(repeats) && policy.repeats.allows(repeats))
+                .map(|repeats| len / repeats)
+                .filter(|block| {
+                    policy
+                        .blocks
+                        .as_ref()
+                        .is_none_or(|blocks| blocks.contains(block))
+                })
+                .any(|block| {
+                    digits
+                        .chunks(block as usize)
+                        .all(|chunk| chunk == &digits[..block as usize])
+                })
+        };
+
+        ranges
+            .values()
+            .filter(is_invalid)
+            .map(|id| id as u128)
+            .sum()
+    }
+
+    #[test]
+    fn matches_scanning() {
+        let ranges: IntervalSet<u64> = [1..=200_000, 1_000_000..=1_300_000, 9_999_990..=10_000_010]
+            .into_iter()
+            .collect();
+        let policies = [
+            Policy::halves(),
+            Policy::repeated(),
+            Policy {
+                repeats: Repeats::Exactly(3),
+                ..Policy::repeated()
+            },
+            Policy {
+                repeats: Repeats::AtLeast(3),
+                ..Policy::repeated()
+            },
+            Policy {
+                blocks: Some(vec![1, 3]),
+                ..Policy::repeated()
+            },
+            Policy {
+                radix: 16,
+                ..Policy::repeated()
+            },
+            Policy {
+                radix: 2,
+                ..Policy::halves()
+            },
+        ];
+
+        for policy in policies {
+            assert_eq!(
+                solution(&policy, &ranges),
+                scan(&policy, &ranges),
+                "{policy:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn lists_each_invalid_id_once() {
+        let ranges: IntervalSet<u64> = [1..=200_000, 9_999_990..=10_000_010].into_iter().collect();
+
+        for policy in [
+            Policy::halves(),
+            Policy::repeated(),
+            Policy {
+                radix: 16,
+                ..Policy::repeated()
+            },
+        ] {
+            let ids = ranges
+                .iter()
+                .flat_map(|range| policy.invalid_ids(range))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                ids.iter().map(|m| m.id as u128).sum::<u128>(),
+                solution(&policy, &ranges)
+            );
+            assert!(ids.windows(2).all(|pair| pair[0].id < pair[1].id));
+        }
+
+        let ids = Policy::repeated().invalid_ids(&(1111..=1111));
+        assert_eq!(
+            ids,
+            [Match {
+                id: 1111,
+                block: 1,
+                block_len: 1,
+                repeats: 4
+            }]
+        );
+    }
+
+    #[test]
+    fn wide_ranges() {
+        let ranges: IntervalSet<u64> = [0..=u64::MAX].into_iter().collect();
+        assert_eq!(
+            solution(&Policy::halves(), &ranges),
+            12509613850169742155792778978
+        );
+        assert_eq!(
+            solution(&Policy::repeated(), &ranges),
+            12510120345498176585131248687
+        );
+    }
+
+    #[test]
+    fn configures_policies() {
+        let mut options = Options::new();
+        for option in ["part1=3", "part2=2+", "blocks=2", "radix=16"] {
+            options.insert(option).unwrap();
+        }
+
+        let mut day = Day2::default();
+        day.configure(&options).unwrap();
+        assert_eq!(
+            day.part1,
+            Policy {
+                repeats: Repeats::Exactly(3),
+                blocks: Some(vec![2]),
+                radix: 16
+            }
+        );
+        assert_eq!(day.parse("a-ff").unwrap().listed, [10..=255]);
+    }
+
+    #[test]
+    fn reports_ranges_as_listed() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-day2-matches-{}.json", std::process::id()));
+        let mut options = Options::new();
+        options
+            .insert(&format!("matches={}", path.display()))
+            .unwrap();
+
+        let mut day = Day2::default();
+        day.configure(&options).unwrap();
+        let ranges = day.parse("11-22,20-33").unwrap();
+        assert_eq!(day.part1(&ranges), 11 + 22 + 33);
+        assert!(!part_path(&path, 1).exists());
+
+        day.report(&ranges);
+        let listings: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(part_path(&path, 1)).unwrap()).unwrap();
+        for part in [1, 2] {
+            std::fs::remove_file(part_path(&path, part)).unwrap();
+        }
+        let listed = listings
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|listing| listing["range"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(listed, ["11-22", "20-33"]);
+        assert_eq!(listings[1]["matches"].as_array().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt::{self, Display};
+
+use aoc_common::{Answer, OptionError, Options, ParseError, Solution};
+use num_bigint::BigUint;
+
+/// Whether to turn on the batteries giving the largest or the smallest
+/// joltage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pick {
+    Largest,
+    Smallest,
+}
+
+/// Turns on `k` batteries of `bank`, keeping their order, so that their
+/// digits read as the largest (or smallest) possible number in `radix`.
+///
+/// The picked digits are kept on a stack. Every new digit first pops the
+/// worse digits before it, as long as enough digits are left in the bank to
+/// still fill up all `k` places, so each digit is pushed and popped at most
+/// once. `stack` is only passed in so that its allocation can be reused
+/// across banks.
+fn joltage(bank: &[u8], k: usize, radix: u32, pick: Pick, stack: &mut Vec<u8>) -> u128 {
+    stack.clear();
+
+    for (i, &digit) in bank.iter().enumerate() {
+        let remaining = bank.len() - i;
+        while let Some(&top) = stack.last()
+            && stack.len() + remaining > k
+            && match pick {
+                Pick::Largest => top < digit,
+                Pick::Smallest => top > digit,
+            }
+        {
+            stack.pop();
+        }
+
+        if stack.len() < k {
+            stack.push(digit);
+        }
+    }
+
+    stack
+        .iter()
+        .fold(0, |total, &digit| total * radix as u128 + digit as u128)
+}
+
+/// The total joltage of all banks. A single bank always fits a `u128`, but
+/// with many batteries turned on the sum of the banks may not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Joltage(pub BigUint);
+
+impl From<u128> for Joltage {
+    fn from(joltage: u128) -> Self {
+        Joltage(BigUint::from(joltage))
+    }
+}
+
+impl Display for Joltage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Answer for Joltage {}
+
+fn solution(banks: &[Vec<u8>], k: usize, radix: u32, pick: Pick) -> Joltage {
+    let mut stack = Vec::with_capacity(k);
+    let total = banks
+        .iter()
+        .map(|bank| BigUint::from(joltage(bank, k, radix, pick, &mut stack)))
+        .sum();
+    Joltage(total)
+}
+
+/// The most digits a joltage can have in `radix` and still fit a `u128`.
+fn max_digits(radix: u32) -> usize {
+    u128::MAX.ilog(radix as u128) as usize
+}
+
+/// Parses the banks into their digits, each bank needs at least `k`
+/// batteries.
+fn parse(contents: &str, k: usize, radix: u32) -> Result<Vec<Vec<u8>>, ParseError> {
+    contents
+        .trim()
+        .split('\n')
+        .map(|bank| {
+            let digits = bank
+                .char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(radix).map(|digit| digit as u8).ok_or_else(|| {
+                        let expected = match radix {
+                            10 => "a digit".to_owned(),
+                            _ => format!("a digit in base {radix}"),
+                        };
+                        ParseError::at(
+                            contents,
+                            &bank[i..i + c.len_utf8()],
+                            format!("invalid battery {c:?}, expected {expected}"),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<u8>, _>>()?;
+
+            if digits.len() < k {
+                return Err(ParseError::at(
+                    contents,
+                    bank,
+                    format!("expected at least {k} batteries, found {}", digits.len()),
+                ));
+            }
+
+            Ok(digits)
+        })
+        .collect()
+}
+
+pub struct Day3 {
+    /// How many batteries each part turns on.
+    k: [usize; 2],
+    radix: u32,
+    pick: Pick,
+}
+
+impl Default for Day3 {
+    fn default() -> Self {
+        Day3 {
+            k: [2, 12],
+            radix: 10,
+            pick: Pick::Largest,
+        }
+    }
+}
+
+impl Solution for Day3 {
+    type Input<'a> = Vec<Vec<u8>>;
+    type Part1 = Joltage;
+    type Part2 = Joltage;
+
+    /// Takes the number of batteries to turn on as `part1` and `part2`, the
+    /// `radix` of the digits, e.g. 16 for hex banks, and `pick=smallest` to
+    /// go for the lowest joltage instead.
+    fn configure(&mut self, options: &Options) -> Result<(), OptionError> {
+        options.only(&["part1", "part2", "radix", "pick"])?;
+
+        self.radix = options.get("radix")?.unwrap_or(self.radix);
+        if !(2..=36).contains(&self.radix) {
+            return Err(OptionError::invalid(
+                "radix",
+                self.radix,
+                "a radix from 2 to 36",
+            ));
+        }
+
+        let max = max_digits(self.radix);
+        for (key, k) in ["part1", "part2"].into_iter().zip(&mut self.k) {
+            *k = options.get(key)?.unwrap_or(*k);
+            if !(1..=max).contains(k) {
+                return Err(OptionError::invalid(
+                    key,
+                    k,
+                    format!("a number of batteries from 1 to {max}"),
+                ));
+            }
+        }
+
+        self.pick = match options.get::<String>("pick")?.as_deref() {
+            None | Some("largest") => Pick::Largest,
+            Some("smallest") => Pick::Smallest,
+            Some(value) => {
+                return Err(OptionError::invalid("pick", value, "largest or smallest"));
+            }
+        };
+
+        Ok(())
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input, self.k[0].max(self.k[1]), self.radix)
+    }
+
+    fn part1(&self, banks: &Self::Input<'_>) -> Self::Part1 {
+        solution(banks, self.k[0], self.radix, self.pick)
+    }
+
+    fn part2(&self, banks: &Self::Input<'_>) -> Self::Part2 {
+        solution(banks, self.k[1], self.radix, self.pick)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(
+            solution(&parse(EXAMPLE, 2, 10).unwrap(), 2, 10, Pick::Largest),
+            357.into()
+        );
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(
+            solution(&parse(EXAMPLE, 12, 10).unwrap(), 12, 10, Pick::Largest),
+            3121910778619.into()
+        );
+    }
+
+    fn joltage_of(bank: &str, k: usize, radix: u32, pick: Pick) -> u128 {
+        let banks = parse(bank, k, radix).unwrap();
+        joltage(&banks[0], k, radix, pick, &mut Vec::new())
+    }
+
+    #[test]
+    fn picks_any_number_of_batteries() {
+        assert_eq!(joltage_of("818181911112111", 1, 10, Pick::Largest), 9);
+        assert_eq!(
+            joltage_of("818181911112111", 15, 10, Pick::Largest),
+            818181911112111
+        );
+        assert_eq!(joltage_of("818181911112111", 3, 10, Pick::Smallest), 111);
+        assert_eq!(joltage_of("987654321111111", 4, 10, Pick::Smallest), 1111);
+        assert_eq!(joltage_of("234234234234278", 5, 10, Pick::Smallest), 22222);
+    }
+
+    #[test]
+    fn other_digits() {
+        assert_eq!(joltage_of("1020304", 4, 10, Pick::Smallest), 4);
+        assert_eq!(joltage_of("1f0a9e", 2, 16, Pick::Largest), 0xfe);
+        assert_eq!(
+            joltage_of(&"9".repeat(30), 25, 10, Pick::Largest),
+            10_u128.pow(25) - 1
+        );
+    }
+
+    #[test]
+    fn totals_beyond_u128() {
+        let k = max_digits(10);
+        let banks = parse(&format!("{}\n", "9".repeat(k)).repeat(4), k, 10).unwrap();
+
+        let joltage = BigUint::from(10_u128.pow(k as u32) - 1);
+        assert_eq!(
+            solution(&banks, k, 10, Pick::Largest),
+            Joltage(joltage * 4_u32)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_battery() {
+        let error = parse("123\n45x6\n", 2, 10).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "x"));
+        assert!(parse("12ab", 2, 16).is_ok());
+    }
+}
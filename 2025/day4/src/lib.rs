@@ -0,0 +1,349 @@
+pub mod visualize;
+
+use std::{path::PathBuf, time::Duration};
+
+use aoc_common::{
+    Grid, OptionError, Options, ParseError, Solution,
+    automaton::{Alphabet, Automaton, Boundary, Neighbourhood, Update},
+};
+
+/// Rolls of paper and the empty floor.
+const ROLLS: Alphabet = Alphabet {
+    alive: '@',
+    dead: '.',
+};
+
+/// A roll can be taken by a forklift when fewer than four of the eight rolls
+/// around it are there, and taking rolls never puts any back.
+fn forklifts() -> Automaton {
+    Automaton {
+        neighbourhood: Neighbourhood::Moore(1),
+        rule: "B/S45678".parse().unwrap(),
+        update: Update::Synchronous,
+        boundary: Boundary::Dead,
+    }
+}
+
+/// The cells that change in each generation, up to `limit` generations or
+/// until nothing changes anymore. For the forklifts those are the waves of
+/// removed rolls.
+fn erode(
+    automaton: &Automaton,
+    table: &Grid<bool>,
+    limit: Option<usize>,
+) -> Vec<Vec<(isize, isize)>> {
+    automaton
+        .simulate(table.clone())
+        .take(limit.unwrap_or(usize::MAX))
+        .collect()
+}
+
+fn solution1(automaton: &Automaton, table: &Grid<bool>) -> usize {
+    automaton
+        .simulate(table.clone())
+        .next()
+        .map_or(0, |wave| wave.len())
+}
+
+fn solution2(waves: &[Vec<(isize, isize)>]) -> usize {
+    waves.iter().map(Vec::len).sum()
+}
+
+fn parse(contents: &str) -> Result<Grid<bool>, ParseError> {
+    ROLLS.parse(contents)
+}
+
+pub struct Day4 {
+    automaton: Automaton,
+    /// Stops part 2 after this many generations, for rules that never settle.
+    limit: Option<usize>,
+    /// 1 lists the removals of every wave on stderr.
+    verbosity: u8,
+    /// Where to write a PPM image of every wave.
+    frames: Option<PathBuf>,
+    /// The size of a cell in the images, in pixels.
+    scale: usize,
+    /// Plays the waves back in the terminal, one frame every `delay`.
+    play: bool,
+    delay: Duration,
+}
+
+impl Default for Day4 {
+    fn default() -> Self {
+        Day4 {
+            automaton: forklifts(),
+            limit: None,
+            verbosity: 0,
+            frames: None,
+            scale: 4,
+            play: false,
+            delay: Duration::from_millis(100),
+        }
+    }
+}
+
+impl Solution for Day4 {
+    type Input<'a> = Grid<bool>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Takes another automaton as `rule` (like `B3/S23`, or `B/S10-24` for
+    /// counts above 9), `neighbourhood` (like `moore:2` or `von-neumann`) and
+    /// `update` (`sync` or `async`), with a `limit` on the generations of part
+    /// 2. `boundary` sets what lies beyond the table: `dead` floor, a `torus`
+    /// wrapping around to the other side, or an `infinite` plane.
+    ///
+    /// `verbose=1` lists the waves of part 2, `frames=<dir>` writes them
+    /// as PPM images of `scale` pixels per cell, and `play=true` plays them
+    /// back in the terminal with `delay` milliseconds between frames.
+    fn configure(&mut self, options: &Options) -> Result<(), OptionError> {
+        options.only(&[
+            "rule",
+            "neighbourhood",
+            "update",
+            "boundary",
+            "limit",
+            "verbose",
+            "frames",
+            "scale",
+            "play",
+            "delay",
+        ])?;
+
+        if let Some(rule) = options.get::<String>("rule")? {
+            self.automaton.rule = rule
+                .parse()
+                .map_err(|_| OptionError::invalid("rule", rule, "a rule like B3/S23"))?;
+        }
+        if let Some(neighbourhood) = options.get::<String>("neighbourhood")? {
+            self.automaton.neighbourhood = neighbourhood.parse().map_err(|_| {
+                OptionError::invalid(
+                    "neighbourhood",
+                    neighbourhood,
+                    "moore or von-neumann, like moore:2",
+                )
+            })?;
+        }
+        if let Some(update) = options.get::<String>("update")? {
+            self.automaton.update = update
+                .parse()
+                .map_err(|_| OptionError::invalid("update", update, "sync or async"))?;
+        }
+        if let Some(boundary) = options.get::<String>("boundary")? {
+            self.automaton.boundary = boundary.parse().map_err(|_| {
+                OptionError::invalid("boundary", boundary, "dead, torus or infinite")
+            })?;
+        }
+        self.limit = options.get("limit")?.or(self.limit);
+
+        self.verbosity = options.get("verbose")?.unwrap_or(self.verbosity);
+        self.frames = options.get("frames")?.or(self.frames.take());
+        self.scale = options.get("scale")?.unwrap_or(self.scale).max(1);
+        self.play = options.get("play")?.unwrap_or(self.play);
+        if let Some(delay) = options.get("delay")? {
+            self.delay = Duration::from_millis(delay);
+        }
+
+        Ok(())
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, table: &Self::Input<'_>) -> Self::Part1 {
+        solution1(&self.automaton, table)
+    }
+
+    fn part2(&self, table: &Self::Input<'_>) -> Self::Part2 {
+        solution2(&erode(&self.automaton, table, self.limit))
+    }
+
+    /// Shows the waves of part 2 as far as the options ask for it, running
+    /// the erosion once more so that none of this is timed with part 2.
+    fn report(&self, table: &Self::Input<'_>) {
+        if self.verbosity == 0 && self.frames.is_none() && !self.play {
+            return;
+        }
+
+        let waves = erode(&self.automaton, table, self.limit);
+        if self.verbosity >= 1 {
+            for (i, wave) in waves.iter().enumerate() {
+                eprintln!("wave {}: {} changed", i + 1, wave.len());
+            }
+            eprintln!("{} waves", waves.len());
+        }
+
+        if self.frames.is_none() && !self.play {
+            return;
+        }
+
+        let frames = visualize::frames(table, &waves);
+        if let Some(dir) = &self.frames
+            && let Err(err) = visualize::write_frames(dir, &frames, self.scale)
+        {
+            eprintln!("could not write frames to {}: {}", dir.display(), err);
+        }
+        if self.play {
+            // Played on stderr, so the answers on stdout stay clean.
+            let _ = visualize::play(&frames, self.delay, &mut std::io::stderr());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(solution1(&forklifts(), &parse(EXAMPLE).unwrap()), 13);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(
+            solution2(&erode(&forklifts(), &parse(EXAMPLE).unwrap(), None)),
+            43
+        );
+    }
+
+    #[test]
+    fn erodes_in_waves() {
+        let waves = erode(&forklifts(), &parse(EXAMPLE).unwrap(), None);
+        let removed = waves.iter().map(Vec::len).collect::<Vec<_>>();
+        assert_eq!(removed, [13, 12, 7, 5, 2, 1, 1, 1, 1]);
+    }
+
+    /// The first version, rescanning the whole grid for every wave.
+    fn rescan(mut table: Grid<bool>) -> Vec<usize> {
+        let count_neighbors =
+            |table: &Grid<bool>, x, y| table.neighbours8(x, y).filter(|&p| table[p]).count();
+
+        let mut waves = Vec::new();
+        loop {
+            let wave = table
+                .positions()
+                .filter(|&(x, y)| table[(x, y)] && count_neighbors(&table, x, y) < 4)
+                .collect::<Vec<_>>();
+            if wave.is_empty() {
+                return waves;
+            }
+            for &position in &wave {
+                table[position] = false;
+            }
+            waves.push(wave.len());
+        }
+    }
+
+    #[test]
+    fn matches_rescanning() {
+        let mut seed = 0x2545f491_u32;
+        let table = Grid::from_fn(40, 30, |_, _| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            !seed.is_multiple_of(4)
+        });
+
+        let waves = erode(&forklifts(), &table, None)
+            .iter()
+            .map(Vec::len)
+            .collect::<Vec<_>>();
+        assert_eq!(waves, rescan(table));
+    }
+
+    /// Removes rolls one at a time in reading order, each wave being one
+    /// sweep over the whole table.
+    fn sweep(mut table: Grid<bool>) -> Vec<usize> {
+        let mut waves = Vec::new();
+        loop {
+            let mut removed = 0;
+            for (x, y) in table.positions() {
+                if table[(x, y)] && table.neighbours8(x, y).filter(|&p| table[p]).count() < 4 {
+                    table[(x, y)] = false;
+                    removed += 1;
+                }
+            }
+            if removed == 0 {
+                return waves;
+            }
+            waves.push(removed);
+        }
+    }
+
+    #[test]
+    fn asynchronous_removal_sweeps_in_reading_order() {
+        let automaton = Automaton {
+            update: Update::Asynchronous,
+            ..forklifts()
+        };
+        let waves = |table: &Grid<bool>| {
+            erode(&automaton, table, None)
+                .iter()
+                .map(Vec::len)
+                .collect::<Vec<_>>()
+        };
+
+        let table = parse(EXAMPLE).unwrap();
+        assert_eq!(waves(&table), [30, 9, 4]);
+        assert_eq!(waves(&table), sweep(table));
+
+        let mut seed = 0x9e3779b9_u32;
+        let table = Grid::from_fn(40, 30, |_, _| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            !seed.is_multiple_of(4)
+        });
+        assert_eq!(waves(&table), sweep(table));
+    }
+
+    #[test]
+    fn boundaries() {
+        let table = parse(EXAMPLE).unwrap();
+        let infinite = Automaton {
+            boundary: Boundary::Infinite,
+            ..forklifts()
+        };
+        assert_eq!(
+            erode(&infinite, &table, None),
+            erode(&forklifts(), &table, None)
+        );
+
+        // On a torus every roll of a full table has eight neighbours.
+        let full = parse("@@@\n@@@\n@@@\n").unwrap();
+        let torus = Automaton {
+            boundary: Boundary::Torus,
+            ..forklifts()
+        };
+        assert_eq!(solution2(&erode(&torus, &full, None)), 0);
+        assert_eq!(solution2(&erode(&forklifts(), &full, None)), 9);
+
+        // A torus smaller than the neighbourhood must not count a roll as its
+        // own neighbour, or the same neighbour more than once.
+        let lone = parse("@\n").unwrap();
+        assert_eq!(solution2(&erode(&torus, &lone, None)), 1);
+        let strip = parse("@@@@@\n").unwrap();
+        assert_eq!(solution2(&erode(&torus, &strip, None)), 5);
+    }
+
+    #[test]
+    fn rejects_invalid_cell() {
+        let error = parse("..@\n.#@\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "#"));
+    }
+}
@@ -0,0 +1,135 @@
+//! Frames of the erosion, written out as PPM images or played back in the
+//! terminal, with the rolls removed in each wave highlighted.
+
+use std::{
+    io::{self, Write},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use aoc_common::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Roll,
+    /// Removed in the wave the frame shows.
+    Removed,
+}
+
+impl Cell {
+    fn rgb(self) -> [u8; 3] {
+        match self {
+            Cell::Empty => [0x10, 0x10, 0x10],
+            Cell::Roll => [0xd0, 0xd0, 0xd0],
+            Cell::Removed => [0xe0, 0x30, 0x30],
+        }
+    }
+}
+
+/// The starting grid followed by one frame per wave, where a wave lists the
+/// cells that changed. Rules with births just show the new rolls, and only
+/// the area of the table is drawn, even if the cells spread beyond it.
+pub fn frames(table: &Grid<bool>, waves: &[Vec<(isize, isize)>]) -> Vec<Grid<Cell>> {
+    let mut rolls = table.clone();
+    let mut frames = vec![table.map(|&roll| if roll { Cell::Roll } else { Cell::Empty })];
+
+    for wave in waves {
+        let mut frame = rolls.map(|&roll| if roll { Cell::Roll } else { Cell::Empty });
+        for &(x, y) in wave.iter().filter(|&&(x, y)| table.contains(x, y)) {
+            let position = (x as usize, y as usize);
+            rolls[position] = !rolls[position];
+            frame[position] = if rolls[position] {
+                Cell::Roll
+            } else {
+                Cell::Removed
+            };
+        }
+        frames.push(frame);
+    }
+
+    frames
+}
+
+/// Writes a binary PPM with every cell drawn as a `scale` by `scale` square.
+pub fn write_ppm(frame: &Grid<Cell>, scale: usize, out: &mut impl Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "P6\n{} {}\n255",
+        frame.width() * scale,
+        frame.height() * scale
+    )?;
+
+    for row in frame.rows() {
+        let line = row
+            .iter()
+            .flat_map(|cell| cell.rgb().repeat(scale))
+            .collect::<Vec<_>>();
+        for _ in 0..scale {
+            out.write_all(&line)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the frames to `dir` as `wave000.ppm`, `wave001.ppm` and so on,
+/// ready for e.g. `ffmpeg -i wave%03d.ppm erosion.gif`.
+pub fn write_frames(dir: &Path, frames: &[Grid<Cell>], scale: usize) -> io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+
+    for (i, frame) in frames.iter().enumerate() {
+        let mut file =
+            io::BufWriter::new(std::fs::File::create(dir.join(format!("wave{i:03}.ppm")))?);
+        write_ppm(frame, scale, &mut file)?;
+        file.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Plays the frames back with ANSI escapes, redrawing in place every `delay`.
+pub fn play(frames: &[Grid<Cell>], delay: Duration, out: &mut impl Write) -> io::Result<()> {
+    for (i, frame) in frames.iter().enumerate() {
+        let mut screen = format!("\x1b[2J\x1b[Hwave {i}/{}\n", frames.len() - 1);
+        for row in frame.rows() {
+            for cell in row {
+                screen += match cell {
+                    Cell::Empty => "\x1b[2m.\x1b[0m",
+                    Cell::Roll => "@",
+                    Cell::Removed => "\x1b[1;31mx\x1b[0m",
+                };
+            }
+            screen.push('\n');
+        }
+
+        out.write_all(screen.as_bytes())?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights_each_wave() {
+        let table = Grid::parse("@@.\n@@@\n", |c| Ok(c == '@')).unwrap();
+        let frames = frames(&table, &[vec![(0, 0), (2, 1)], vec![(1, 0), (5, -1)]]);
+
+        let render = |frame: &Grid<Cell>| frame.render(|cell| ['.', '@', 'x'][*cell as usize]);
+        assert_eq!(
+            frames.iter().map(render).collect::<Vec<_>>(),
+            ["@@.\n@@@\n", "x@.\n@@x\n", ".x.\n@@.\n"]
+        );
+
+        let mut ppm = Vec::new();
+        write_ppm(&frames[1], 2, &mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n6 4\n255\n"));
+        assert_eq!(ppm.len(), "P6\n6 4\n255\n".len() + 6 * 4 * 3);
+    }
+}
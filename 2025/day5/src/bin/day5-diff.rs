@@ -0,0 +1,55 @@
+//! Shows how the fresh ranges changed between two inventories. Either file
+//! can be a whole puzzle input, only the ranges are compared.
+//!
+//! ```sh
+//! cargo run -p aoc2025-day5 --bin day5-diff -- yesterday.txt today.txt
+//! cargo run -p aoc2025-day5 --bin day5-diff -- yesterday.txt today.txt --json -o merge-adjacent=true
+//! ```
+
+use std::process::ExitCode;
+
+use aoc_common::{Options, Solution};
+use aoc2025_day5::{Day5, diff};
+
+const USAGE: &str = "usage: day5-diff <old> <new> [--json] [-o <key>=<value>]...";
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (rest, options) = Options::split_args(args).map_err(|err| err.to_string())?;
+
+    let mut day = Day5::default();
+    day.configure(&options).map_err(|err| err.to_string())?;
+
+    let read = |path: &str| {
+        let contents =
+            std::fs::read_to_string(path).map_err(|err| format!("could not read {path}: {err}"))?;
+        day.read_ranges(&contents)
+            .map_err(|err| format!("{path}: {}", err.diagnostic(&contents)))
+    };
+
+    let (old, new, json) = match rest.as_slice() {
+        [old, new] => (old, new, false),
+        [old, new, "--json"] => (old, new, true),
+        _ => return Err(USAGE.to_owned()),
+    };
+    let diff = diff::diff(&read(old)?, &read(new)?);
+
+    if json {
+        println!("{}", diff.to_json());
+    } else {
+        print!("{}", diff.to_text());
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Compares two lists of fresh ranges, e.g. yesterday's database against
+//! today's, and shows which ids became fresh, which spoiled and which stayed.
+
+use aoc_common::IntervalSet;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diff {
+    /// Fresh in the new list only.
+    pub added: IntervalSet<usize>,
+    /// Fresh in the old list only.
+    pub removed: IntervalSet<usize>,
+    pub common: IntervalSet<usize>,
+}
+
+pub fn diff(old: &IntervalSet<usize>, new: &IntervalSet<usize>) -> Diff {
+    Diff {
+        added: new.difference(old),
+        removed: old.difference(new),
+        common: old.intersection(new),
+    }
+}
+
+/// One part of the diff as it is written to JSON.
+#[derive(Serialize)]
+struct Section {
+    ranges: Vec<[usize; 2]>,
+    ids: u128,
+}
+
+impl Section {
+    fn new(set: &IntervalSet<usize>) -> Self {
+        Section {
+            ranges: set
+                .iter()
+                .map(|range| [*range.start(), *range.end()])
+                .collect(),
+            ids: ids(set),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Listing {
+    added: Section,
+    removed: Section,
+    common: Section,
+}
+
+fn ids(set: &IntervalSet<usize>) -> u128 {
+    set.total_len()
+        .expect("a set of usize holds fewer than 2^128 values")
+}
+
+impl Diff {
+    /// A summary line per part followed by its ranges, marked `+`, `-` and `=`
+    /// like a line diff.
+    pub fn to_text(&self) -> String {
+        let parts = [
+            ("added", '+', &self.added),
+            ("removed", '-', &self.removed),
+            ("common", '=', &self.common),
+        ];
+
+        let mut text = String::new();
+        for (name, marker, set) in parts {
+            let plural = if set.len() == 1 { "" } else { "s" };
+            text += &format!("{name}: {} range{plural}, {} ids\n", set.len(), ids(set));
+            for range in set {
+                text += &format!("{marker} {}-{}\n", range.start(), range.end());
+            }
+        }
+        text
+    }
+
+    pub fn to_json(&self) -> String {
+        let listing = Listing {
+            added: Section::new(&self.added),
+            removed: Section::new(&self.removed),
+            common: Section::new(&self.common),
+        };
+        serde_json::to_string_pretty(&listing).expect("diffs always serialize")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_into_added_removed_and_common() {
+        let old = [3..=5, 10..=14, 16..=20].into_iter().collect();
+        let new = [4..=5, 12..=22].into_iter().collect();
+        let diff = diff(&old, &new);
+
+        assert_eq!(
+            diff.to_text(),
+            "\
+added: 2 ranges, 3 ids
++ 15-15
++ 21-22
+removed: 2 ranges, 3 ids
+- 3-3
+- 10-11
+common: 3 ranges, 10 ids
+= 4-5
+= 12-14
+= 16-20
+"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&diff.to_json()).unwrap();
+        assert_eq!(
+            json["added"]["ranges"],
+            serde_json::json!([[15, 15], [21, 22]])
+        );
+        assert_eq!(json["common"]["ids"], 10);
+    }
+}
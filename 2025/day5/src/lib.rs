@@ -0,0 +1,218 @@
+pub mod diff;
+
+use aoc_common::{IntervalSet, OptionError, Options, ParseError, Solution, parse};
+
+/// Parses one `<start>-<end>` range per line of `fresh_ranges`, a slice of
+/// `contents`. Ranges that touch stay apart unless `merge_adjacent` is set.
+fn parse_ranges(
+    contents: &str,
+    fresh_ranges: &str,
+    merge_adjacent: bool,
+) -> Result<IntervalSet<usize>, ParseError> {
+    let mut ranges = fresh_ranges
+        .lines()
+        .map(|range| {
+            let (start, end) = range.split_once('-').ok_or_else(|| {
+                ParseError::at(contents, range, "invalid range, expected <start>-<end>")
+            })?;
+            let (start, end) = (
+                parse::number(contents, start)?,
+                parse::number(contents, end)?,
+            );
+            if start > end {
+                return Err(ParseError::at(
+                    contents,
+                    range,
+                    "invalid range, the end is before the start",
+                ));
+            }
+
+            Ok(start..=end)
+        })
+        .collect::<Result<IntervalSet<usize>, _>>()?;
+
+    if merge_adjacent {
+        ranges.merge_adjacent();
+    }
+    Ok(ranges)
+}
+
+fn solution1(fresh_ingredient_ranges: &IntervalSet<usize>, ingredients: &[usize]) -> usize {
+    fresh_ingredient_ranges
+        .covering_all(ingredients)
+        .iter()
+        .filter(|range| range.is_some())
+        .count()
+}
+
+fn solution2(fresh_ingredient_ranges: &IntervalSet<usize>) -> u128 {
+    fresh_ingredient_ranges
+        .total_len()
+        .expect("a set of usize holds fewer than 2^128 values")
+}
+
+fn parse(
+    contents: &str,
+    merge_adjacent: bool,
+) -> Result<(IntervalSet<usize>, Vec<usize>), ParseError> {
+    let trimmed = contents.trim();
+
+    let (fresh_ingredients, ingredients) = trimmed.split_once("\n\n").ok_or_else(|| {
+        ParseError::at(
+            contents,
+            &trimmed[trimmed.len()..],
+            "expected a blank line between the fresh ranges and the ingredients",
+        )
+    })?;
+
+    let ingredients = ingredients
+        .lines()
+        .map(|ingredient| parse::number(contents, ingredient))
+        .collect::<Result<Vec<usize>, _>>()?;
+    let fresh_ingredients = parse_ranges(contents, fresh_ingredients, merge_adjacent)?;
+
+    Ok((fresh_ingredients, ingredients))
+}
+
+#[derive(Default)]
+pub struct Day5 {
+    /// 1 lists every ingredient of part 1 with its fresh range, or how far it
+    /// is from the closest one, on stderr.
+    verbosity: u8,
+    /// Treats touching ranges like `3-5` and `6-8` as one range.
+    merge_adjacent: bool,
+}
+
+impl Day5 {
+    /// Reads only the fresh ranges of `contents`, which may be a whole puzzle
+    /// input or a file of nothing but ranges.
+    pub fn read_ranges(&self, contents: &str) -> Result<IntervalSet<usize>, ParseError> {
+        let trimmed = contents.trim();
+        let fresh_ranges = trimmed
+            .split_once("\n\n")
+            .map_or(trimmed, |(fresh_ranges, _)| fresh_ranges);
+        parse_ranges(contents, fresh_ranges, self.merge_adjacent)
+    }
+
+    fn list_ingredients(
+        &self,
+        fresh_ingredient_ranges: &IntervalSet<usize>,
+        ingredients: &[usize],
+    ) {
+        let covering = fresh_ingredient_ranges.covering_all(ingredients);
+        for (&ingredient, range) in ingredients.iter().zip(covering) {
+            match range {
+                Some(range) => {
+                    eprintln!("{ingredient}: fresh in {}-{}", range.start(), range.end())
+                }
+                None => match fresh_ingredient_ranges.distance(ingredient) {
+                    Some(distance) => {
+                        eprintln!("{ingredient}: spoiled, {distance} from the closest fresh range")
+                    }
+                    None => eprintln!("{ingredient}: spoiled, there are no fresh ranges"),
+                },
+            }
+        }
+    }
+}
+
+impl Solution for Day5 {
+    type Input<'a> = (IntervalSet<usize>, Vec<usize>);
+    type Part1 = usize;
+    type Part2 = u128;
+
+    /// `verbose=1` lists which range every ingredient is fresh in, and
+    /// `merge-adjacent=true` joins ranges that touch before that.
+    fn configure(&mut self, options: &Options) -> Result<(), OptionError> {
+        options.only(&["verbose", "merge-adjacent"])?;
+        self.verbosity = options.get("verbose")?.unwrap_or(self.verbosity);
+        self.merge_adjacent = options
+            .get("merge-adjacent")?
+            .unwrap_or(self.merge_adjacent);
+        Ok(())
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input, self.merge_adjacent)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Self::Part1 {
+        solution1(&input.0, &input.1)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Self::Part2 {
+        solution2(&input.0)
+    }
+
+    fn report(&self, input: &Self::Input<'_>) {
+        if self.verbosity >= 1 {
+            self.list_ingredients(&input.0, &input.1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    #[test]
+    fn example_part1() {
+        let (fresh_ingredients, ingredients) = parse(EXAMPLE, false).unwrap();
+        assert_eq!(solution1(&fresh_ingredients, &ingredients), 3);
+    }
+
+    #[test]
+    fn example_part2() {
+        let (fresh_ingredients, _) = parse(EXAMPLE, false).unwrap();
+        assert_eq!(solution2(&fresh_ingredients), 14);
+    }
+
+    #[test]
+    fn merges_adjacent_ranges_on_request() {
+        let contents = "0-2\n3-5\n7-7\n\n1\n";
+        let (apart, _) = parse(contents, false).unwrap();
+        let (merged, _) = parse(contents, true).unwrap();
+
+        assert_eq!(
+            apart.iter().cloned().collect::<Vec<_>>(),
+            [0..=2, 3..=5, 7..=7]
+        );
+        assert_eq!(merged.iter().cloned().collect::<Vec<_>>(), [0..=5, 7..=7]);
+        assert_eq!((solution2(&apart), solution2(&merged)), (7, 7));
+    }
+
+    #[test]
+    fn counts_the_whole_domain() {
+        let (fresh_ingredients, _) = parse(&format!("0-{}\n\n0\n", usize::MAX), false).unwrap();
+        assert_eq!(solution2(&fresh_ingredients), 1 << usize::BITS);
+    }
+
+    #[test]
+    fn rejects_malformed_ranges() {
+        let error = parse("3-5\n10\n\n1\n", false).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 1, "10")
+        );
+
+        let error = parse("3-5\n10-x\n\n1\n", false).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 4, "x"));
+
+        let error = parse("5-3\n\n1\n", false).unwrap_err();
+        assert_eq!(error.message, "invalid range, the end is before the start");
+    }
+}
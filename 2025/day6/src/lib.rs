@@ -0,0 +1,202 @@
+use aoc_common::{Grid, ParseError, Solution};
+
+// returns the numeric value of a character '0' to '9'
+fn get_value_from_char(char: u8) -> u8 {
+    char - 48
+}
+
+fn parse_number_from_span(span: &[u8]) -> usize {
+    let mut num = 0_usize;
+
+    for &cell in span {
+        // skip all spaces before and after the number
+        if cell == b' ' {
+            continue;
+        }
+        let value = get_value_from_char(cell);
+        num = num * 10 + value as usize;
+    }
+
+    num
+}
+
+fn solution1(nums: &Grid<u8>) -> usize {
+    let height = nums.height();
+    let width = nums.width();
+    let operators = nums.row(height - 1);
+
+    let mut total = 0_usize;
+
+    let mut i = 0;
+    
+    while i < width {
+        let operator = operators[i];
+
+        assert!(operator != b' ', "Operator cannot be a space at column {}", i);
+
+        let span_width = operators[(i + 1)..]
+            .iter()
+            .position(|&c| c != b' ')
+            .unwrap_or(width - i);
+
+        let mut num = match operator {
+            b'+' => 0_usize,
+            b'*' => 1_usize,
+            _ => unreachable!("parse only lets '+' and '*' through"),
+        };
+
+        for y in 0..height - 1 {
+            let span = &nums.row(y)[i..(i + span_width)];
+
+            let value = parse_number_from_span(span);
+            
+            match operator {
+                b'+' => num += value,
+                b'*' => num *= value,
+                _ => unreachable!("parse only lets '+' and '*' through"),
+            }
+        }
+        total += num;
+        i += span_width + 1;
+    }
+
+    total
+}
+
+fn solution2(lines: &Grid<u8>) -> usize {
+    let height = lines.height();
+    let width = lines.width();
+    let operators = lines.row(height - 1);
+
+    let mut total = 0_usize;
+
+    let mut nums = Vec::new();
+
+    for i in (0..width).rev() {
+        if operators[usize::min(i + 1, width - 1)] != b' ' {
+            continue;
+        }
+
+        let mut num = 0_usize;
+        for &cell in lines.column(i).take(height - 1) {
+            if cell == b' ' {
+                continue;
+            }
+
+            let value = cell - 48;
+            num = num * 10 + value as usize;
+        }
+
+        nums.push(num);
+        
+        let operator = operators[i];
+
+        if operator == b' ' {
+            continue;
+        }
+
+        // println!("Processing column {}: operator = {}, nums = {:?}", i, operator as char, nums);
+
+        total += nums.into_iter().reduce(|acc, x| {
+            match operator {
+                b'+' => acc + x,
+                b'*' => acc * x,
+                _ => unreachable!("parse only lets '+' and '*' through"),
+            }
+        }).unwrap();
+
+        nums = Vec::new();
+    }
+
+    total
+}
+
+fn parse(contents: &str) -> Result<Grid<u8>, ParseError> {
+    let lines: Vec<&str> = contents.trim_end_matches('\n').split('\n').collect();
+
+    let check = |line: &str, allowed: fn(char) -> bool, expected: &str| match line
+        .char_indices()
+        .find(|&(_, c)| !allowed(c))
+    {
+        Some((i, c)) => Err(ParseError::at(
+            contents,
+            &line[i..i + c.len_utf8()],
+            format!("unexpected {c:?}, expected {expected}"),
+        )),
+        None => Ok(()),
+    };
+
+    let (operators, numbers) = lines.split_last().unwrap();
+    for line in numbers {
+        check(
+            line,
+            |c| c.is_ascii_digit() || c == ' ',
+            "a digit or a space",
+        )?;
+    }
+    check(
+        operators,
+        |c| matches!(c, '+' | '*' | ' '),
+        "an operator or a space",
+    )?;
+
+    if numbers.is_empty() {
+        return Err(ParseError::at(
+            contents,
+            operators,
+            "expected rows of numbers above the operators",
+        ));
+    }
+    if operators.starts_with(' ') {
+        return Err(ParseError::at(
+            contents,
+            &operators[..1],
+            "expected an operator in the first column",
+        ));
+    }
+
+    Grid::parse(contents, |c| Ok(c as u8))
+}
+
+#[derive(Default)]
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Self::Part1 {
+        solution1(lines)
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Self::Part2 {
+        solution2(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+";
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(solution1(&parse(EXAMPLE).unwrap()), 4277556);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(solution2(&parse(EXAMPLE).unwrap()), 3263827);
+    }
+}
@@ -0,0 +1,149 @@
+use aoc_common::{Grid, ParseError, Solution};
+
+fn solution1(grid: &Grid<char>) -> usize {
+    let width = grid.width();
+
+    // Get the start index
+    let start = grid.row(0).iter().position(|&c| c == 'S').unwrap();
+
+    let mut beams = vec![0_usize; width];
+    beams[start] = 1;
+
+    let mut total = 0_usize;
+    for row in grid.rows().skip(1) {
+        for (i, &char) in row.iter().enumerate() {
+            match char {
+                '^' => {
+                    if beams[i] != 0 {
+                        total += 1;
+                    }
+                    beams[i - 1] += 1;
+                    beams[i + 1] += 1;
+                    beams[i] = 0;
+                }
+                '.' => {}
+                _ => unreachable!("parse only lets the start through on the first line"),
+            }
+        }
+    }
+
+    total
+}
+
+fn solution2(grid: &Grid<char>) -> usize {
+    let width = grid.width();
+
+    // Get the start index
+    let start = grid.row(0).iter().position(|&c| c == 'S').unwrap();
+
+    // beams[i] = number of ways to reach column i
+    let mut beams = vec![0_usize; width];
+    // we insert the starting possibility
+    beams[start] = 1;
+
+    for row in grid.rows().skip(1) {
+        for (i, &char) in row.iter().enumerate() {
+            match char {
+                '^' => {
+                    let count = beams[i];
+                    beams[i - 1] += count;
+                    beams[i + 1] += count;
+                    beams[i] = 0;
+                }
+                '.' => {}
+                _ => unreachable!("parse only lets the start through on the first line"),
+            }
+        }
+    }
+
+    beams.iter().sum()
+}
+
+fn parse(contents: &str) -> Result<Grid<char>, ParseError> {
+    let grid = Grid::parse(contents, |c| match c {
+        '.' | '^' | 'S' => Ok(c),
+        _ => Err(format!("unexpected {c:?}, expected '.' or '^'")),
+    })?;
+
+    let first_line = contents.lines().next().unwrap_or_default();
+    if first_line.matches('S').count() != 1 {
+        return Err(ParseError::at(
+            contents,
+            first_line,
+            "expected exactly one 'S' on the first line",
+        ));
+    }
+
+    for (line_num, line) in contents.lines().enumerate() {
+        for (i, char) in line.char_indices() {
+            let error = match char {
+                'S' if line_num != 0 => "the start has to be on the first line",
+                '^' if line_num == 0 => "a splitter can not be on the first line",
+                '^' if i == 0 || i == grid.width() - 1 => "a splitter can not be on the edge",
+                _ => continue,
+            };
+            return Err(ParseError::at(
+                contents,
+                &line[i..i + char.len_utf8()],
+                error,
+            ));
+        }
+    }
+
+    Ok(grid)
+}
+
+#[derive(Default)]
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, grid: &Self::Input<'_>) -> Self::Part1 {
+        solution1(grid)
+    }
+
+    fn part2(&self, grid: &Self::Input<'_>) -> Self::Part2 {
+        solution2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(solution1(&parse(EXAMPLE).unwrap()), 21);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(solution2(&parse(EXAMPLE).unwrap()), 40);
+    }
+}
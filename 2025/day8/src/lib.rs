@@ -1,5 +1,6 @@
-use std::{collections::{HashMap, HashSet}, time::Instant};
+use std::collections::{HashMap, HashSet};
 
+use aoc_common::{ParseError, Solution, parse};
 use itertools::Itertools;
 
 fn get_euclidean_distance(a: &[usize; 3], b: &[usize; 3]) -> f64 {
@@ -9,7 +10,7 @@ fn get_euclidean_distance(a: &[usize; 3], b: &[usize; 3]) -> f64 {
     (dx * dx + dy * dy + dz * dz).sqrt()
 }
 
-fn solution1(boxes: &Vec<[usize; 3]>, num_checks: usize, num_results: usize) -> usize {
+fn solution1(boxes: &[[usize; 3]], num_checks: usize, num_results: usize) -> usize {
     let mut existing: HashSet<(usize, usize)> = HashSet::new();
     let mut circuits = HashMap::new();
     let mut id = 0_u16;
@@ -66,15 +67,15 @@ fn solution1(boxes: &Vec<[usize; 3]>, num_checks: usize, num_results: usize) ->
     circuits
         .iter()
         .into_group_map_by(|&(_, v)| v)
-        .iter()
-        .map(|(_, value)| value.len())
-        .sorted_by(|a, b| b.cmp(&a))
+        .values()
+        .map(|value| value.len())
+        .sorted_by(|a, b| b.cmp(a))
         .take(num_results)
         .reduce(|a, b| a * b)
         .unwrap()
 }
 
-fn solution2(boxes: &Vec<[usize; 3]>) -> usize {
+fn solution2(boxes: &[[usize; 3]]) -> usize {
     let mut set = HashSet::new();
 
     for i in 0..boxes.len() {
@@ -88,7 +89,7 @@ fn solution2(boxes: &Vec<[usize; 3]>) -> usize {
 
     let mut last = (0_usize, 0_usize);
 
-    while set.len() > 0 {
+    while !set.is_empty() {
         let mut best = (f64::MAX, (0_usize, 0_usize));
 
         for (i, j) in set.iter() {
@@ -158,28 +159,75 @@ fn solution2(boxes: &Vec<[usize; 3]>) -> usize {
     boxes[last.0][0] * boxes[last.1][0]
 }
 
-fn main() {
-    let file = std::env::args().nth(1).expect("no file given");
-    println!("Reading file: {}", file);
-
-    let contents = std::fs::read_to_string(file).expect("could not read file");
-    let contents = contents.trim_end_matches('\n');
-    let lines = contents
+fn parse(contents: &str) -> Result<Vec<[usize; 3]>, ParseError> {
+    contents
+        .trim_end_matches('\n')
         .split('\n')
         .map(|line| {
             line.split(',')
-                .map(|part| part.parse().unwrap())
-                .collect::<Vec<usize>>()
+                .map(|part| parse::number(contents, part))
+                .collect::<Result<Vec<usize>, _>>()?
                 .try_into()
-                .unwrap()
+                .map_err(|_| ParseError::at(contents, line, "expected three coordinates"))
         })
-        .collect::<Vec<[usize; 3]>>();
+        .collect()
+}
+
+#[derive(Default)]
+pub struct Day8;
 
+impl Solution for Day8 {
+    type Input<'a> = Vec<[usize; 3]>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    // time each solution:
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, boxes: &Self::Input<'_>) -> Self::Part1 {
+        solution1(boxes, 1000, 3)
+    }
 
-    let start = Instant::now();
-    println!("Solution 1: {} in {:?}", solution1(&lines, 1000, 3), start.elapsed());
-    let start = Instant::now();
-    println!("Solution 2: {} in {:?}", solution2(&lines), start.elapsed());
+    fn part2(&self, boxes: &Self::Input<'_>) -> Self::Part2 {
+        solution2(boxes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(solution1(&parse(EXAMPLE).unwrap(), 10, 3), 40);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(solution2(&parse(EXAMPLE).unwrap()), 25272);
+    }
 }
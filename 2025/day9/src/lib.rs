@@ -0,0 +1,190 @@
+use aoc_common::{ParseError, Solution, parse};
+use itertools::Itertools;
+
+#[derive(Debug)]
+pub struct Tile {
+    x: usize,
+    y: usize,
+}
+
+impl Tile {
+    /// Parses a `x,y` line, which has to be a slice of `input`.
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (x, y) = line
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(input, line, "expected a tile as <x>,<y>"))?;
+        Ok(Tile {
+            x: parse::number(input, x)?,
+            y: parse::number(input, y)?,
+        })
+    }
+
+    fn get_size(tile_a: &Tile, tile_b: &Tile) -> usize {
+        let dx = (tile_a.x as isize - tile_b.x as isize).unsigned_abs() + 1;
+        let dy = (tile_a.y as isize - tile_b.y as isize).unsigned_abs() + 1;
+        dx * dy
+    }
+}
+
+impl PartialEq for Tile {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+fn solution1(tiles: &[Tile]) -> usize {
+    let mut max = (usize::MIN, (0_usize, 0_usize));
+
+    for (i, j) in tiles.iter().tuple_combinations::<(_, _)>() {
+        let size = Tile::get_size(i, j);
+        if size > max.0 {
+            max = (size, (i.x, i.y));
+        }
+    }
+
+    max.0
+}
+
+/// An edge of the loop, between two consecutive red tiles.
+#[derive(Debug)]
+struct Line<'a> {
+    start: &'a Tile,
+    end: &'a Tile,
+}
+
+impl<'a> Line<'a> {
+    fn from_tiles(a: &'a Tile, b: &'a Tile) -> Self {
+        Line { start: a, end: b }
+    }
+
+    /// Whether the line runs through the inside of the block spanned by `a`
+    /// and `b`. Running along its border is fine.
+    fn crosses(&self, a: &Tile, b: &Tile) -> bool {
+        self.start.x.max(self.end.x) > a.x.min(b.x)
+            && self.start.x.min(self.end.x) < a.x.max(b.x)
+            && self.start.y.max(self.end.y) > a.y.min(b.y)
+            && self.start.y.min(self.end.y) < a.y.max(b.y)
+    }
+
+    /// Whether the point `(x, y)`, in doubled coordinates, lies on the line.
+    fn touches(&self, x: usize, y: usize) -> bool {
+        (2 * self.start.x.min(self.end.x)..=2 * self.start.x.max(self.end.x)).contains(&x)
+            && (2 * self.start.y.min(self.end.y)..=2 * self.start.y.max(self.end.y)).contains(&y)
+    }
+}
+
+/// Whether the point `(x, y)`, in doubled coordinates so that it can sit
+/// between tiles, is inside the loop or on it. Casts a ray to the right and
+/// counts the vertical lines it passes.
+fn inside(lines: &[Line], x: usize, y: usize) -> bool {
+    if lines.iter().any(|line| line.touches(x, y)) {
+        return true;
+    }
+
+    let crossings = lines
+        .iter()
+        .filter(|line| line.start.x == line.end.x && 2 * line.start.x > x)
+        .filter(|line| {
+            let (low, high) = (line.start.y.min(line.end.y), line.start.y.max(line.end.y));
+            2 * low <= y && y < 2 * high
+        })
+        .count();
+    crossings % 2 == 1
+}
+
+// Just a helper to sort blocks by size
+fn cmp_block(a: &(&Tile, &Tile), b: &(&Tile, &Tile)) -> std::cmp::Ordering {
+    let size_a = Tile::get_size(a.0, a.1);
+    let size_b = Tile::get_size(b.0, b.1);
+    size_a.cmp(&size_b)
+}
+
+fn solution2(tiles: &[Tile]) -> usize {
+    let lines: Vec<_> = tiles
+        .iter()
+        .circular_tuple_windows::<(_, _)>()
+        .map(|(a, b)| Line::from_tiles(a, b))
+        .collect();
+
+    let blocks: Vec<_> = tiles
+        .iter()
+        .tuple_combinations::<(_, _)>()
+        .sorted_by(cmp_block)
+        .rev()
+        .collect();
+
+    // A block is all red and green if no line cuts into it and it is not
+    // outside the loop altogether, like the notch of a U.
+    for (i, j) in blocks {
+        if lines.iter().any(|line| line.crosses(i, j)) {
+            continue;
+        }
+        if inside(&lines, i.x + j.x, i.y + j.y) {
+            return Tile::get_size(i, j);
+        }
+    }
+
+    panic!("No block found");
+}
+
+fn parse(contents: &str) -> Result<Vec<Tile>, ParseError> {
+    contents
+        .trim_end_matches('\n')
+        .split('\n')
+        .map(|line| Tile::parse(contents, line))
+        .collect()
+}
+
+#[derive(Default)]
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&self, tiles: &Self::Input<'_>) -> Self::Part1 {
+        solution1(tiles)
+    }
+
+    fn part2(&self, tiles: &Self::Input<'_>) -> Self::Part2 {
+        solution2(tiles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+";
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(solution1(&parse(EXAMPLE).unwrap()), 50);
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(solution2(&parse(EXAMPLE).unwrap()), 24);
+    }
+
+    #[test]
+    fn skips_blocks_outside_the_loop() {
+        // A U whose notch, spanned by two red tiles, is the biggest block.
+        let tiles = parse("0,0\n2,0\n2,5\n8,5\n8,0\n10,0\n10,8\n0,8\n").unwrap();
+        assert_eq!(solution2(&tiles), 36);
+    }
+}
@@ -0,0 +1,74 @@
+//! Benchmarks the parse step and both parts of every day separately.
+//!
+//! Days without a cached input in `inputs/` are skipped. Criterion keeps the
+//! results of the previous run under `target/criterion` and reports changes
+//! against them, named baselines make comparing a rewrite easier:
+//!
+//! ```sh
+//! cargo bench -p aoc -- --save-baseline before 2025/day8
+//! # ...rewrite day 8...
+//! cargo bench -p aoc -- --baseline before 2025/day8
+//! ```
+
+use std::hint::black_box;
+
+use aoc::inputs::Inputs;
+use aoc_common::Solution;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+fn bench_day<S: Solution + Default>(c: &mut Criterion, year: u16, day: u8) {
+    let inputs = Inputs::from_env();
+    let path = inputs.input_path(year, day);
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        eprintln!(
+            "skipping {year} day {day}, {} does not exist",
+            path.display()
+        );
+        return;
+    };
+
+    let solution = S::default();
+    let input = match solution.parse(&contents) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!(
+                "skipping {year} day {day}, the input does not parse\n{}",
+                err.diagnostic(&contents)
+            );
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("{year}/day{day}"));
+    // Some of the days take seconds per iteration, the default of 100 samples
+    // would keep a full run going for the better part of an hour.
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(&contents))));
+    group.bench_function("part1", |b| b.iter(|| solution.part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| solution.part2(black_box(&input))));
+
+    group.finish();
+}
+
+fn bench_2015(c: &mut Criterion) {
+    bench_day::<aoc2015_day1::Day1>(c, 2015, 1);
+}
+
+fn bench_2025(c: &mut Criterion) {
+    bench_day::<aoc2025_day1::Day1>(c, 2025, 1);
+    bench_day::<aoc2025_day2::Day2>(c, 2025, 2);
+    bench_day::<aoc2025_day3::Day3>(c, 2025, 3);
+    bench_day::<aoc2025_day4::Day4>(c, 2025, 4);
+    bench_day::<aoc2025_day5::Day5>(c, 2025, 5);
+    bench_day::<aoc2025_day6::Day6>(c, 2025, 6);
+    bench_day::<aoc2025_day7::Day7>(c, 2025, 7);
+    bench_day::<aoc2025_day8::Day8>(c, 2025, 8);
+    bench_day::<aoc2025_day9::Day9>(c, 2025, 9);
+    bench_day::<aoc2025_day10::Day10>(c, 2025, 10);
+    bench_day::<aoc2025_day11::Day11>(c, 2025, 11);
+    bench_day::<aoc2025_day12::Day12>(c, 2025, 12);
+}
+
+criterion_group!(benches, bench_2015, bench_2025);
+criterion_main!(benches);
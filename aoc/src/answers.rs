@@ -0,0 +1,283 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// What the puzzle said about a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Hint::TooHigh => "too high",
+            Hint::TooLow => "too low",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+/// Everything known about the answer to one part.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<Guess>,
+}
+
+/// How an answer compares to what is on record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// A different answer is known to be correct.
+    Wrong {
+        expected: String,
+    },
+    /// The answer was guessed before, or is ruled out by the hint of a
+    /// previous guess.
+    Rejected(Guess),
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { expected } => write!(f, "wrong, expected {expected}"),
+            Verdict::Rejected(Guess {
+                value,
+                hint: Some(hint),
+            }) => {
+                write!(f, "wrong, {value} was already {hint}")
+            }
+            Verdict::Rejected(Guess { value, hint: None }) => {
+                write!(f, "wrong, {value} was already rejected")
+            }
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Verdict {
+    /// Whether the answer is known not to be the right one.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong { .. } | Verdict::Rejected(_))
+    }
+}
+
+impl Record {
+    pub fn check(&self, answer: &str) -> Verdict {
+        if let Some(expected) = &self.answer {
+            return if expected == answer {
+                Verdict::Correct
+            } else {
+                Verdict::Wrong {
+                    expected: expected.clone(),
+                }
+            };
+        }
+
+        self.wrong
+            .iter()
+            .find(|guess| guess.value == answer || guess.rules_out(answer))
+            .map_or(Verdict::Unknown, |guess| Verdict::Rejected(guess.clone()))
+    }
+}
+
+impl Guess {
+    /// Whether the hint on this guess means `answer` can not be right either,
+    /// e.g. anything at or above a guess that was too high.
+    fn rules_out(&self, answer: &str) -> bool {
+        let (Ok(guess), Ok(answer)) = (self.value.parse::<i128>(), answer.parse::<i128>()) else {
+            return false;
+        };
+
+        match self.hint {
+            Some(Hint::TooHigh) => answer >= guess,
+            Some(Hint::TooLow) => answer <= guess,
+            None => false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(path, err) => {
+                write!(f, "could not access {}: {}", path.display(), err)
+            }
+            AnswersError::Parse(path, err) => {
+                write!(f, "could not parse {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+const HEADER: &str = "\
+# Known answers for the real inputs, checked by `aoc` after every run.
+# Use `aoc answer` and `aoc wrong` rather than editing this by hand.
+";
+
+/// The answers file, `answers.toml` at the top of the workspace unless moved
+/// with the `AOC_ANSWERS` environment variable.
+///
+/// Parts are keyed as `[<year>.day<N>.part<P>]`, so the file reads naturally
+/// and sorts by year and day.
+pub struct Answers {
+    path: PathBuf,
+    years: BTreeMap<String, BTreeMap<String, BTreeMap<String, Record>>>,
+}
+
+impl Answers {
+    /// Loads the answers at `path`, a missing file has no answers yet.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, AnswersError> {
+        let path = path.into();
+        let years = match std::fs::read_to_string(&path) {
+            Ok(contents) => {
+                toml::from_str(&contents).map_err(|err| AnswersError::Parse(path.clone(), err))?
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(AnswersError::Io(path, err)),
+        };
+
+        Ok(Answers { path, years })
+    }
+
+    pub fn from_env() -> Result<Self, AnswersError> {
+        match std::env::var_os("AOC_ANSWERS") {
+            Some(path) => Answers::load(path),
+            None => Answers::load(Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml")),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: usize) -> Option<&Record> {
+        self.years
+            .get(&year.to_string())?
+            .get(&format!("day{day}"))?
+            .get(&format!("part{part}"))
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: usize, answer: &str) -> Verdict {
+        self.get(year, day, part)
+            .map_or(Verdict::Unknown, |record| record.check(answer))
+    }
+
+    fn record_mut(&mut self, year: u16, day: u8, part: usize) -> &mut Record {
+        self.years
+            .entry(year.to_string())
+            .or_default()
+            .entry(format!("day{day}"))
+            .or_default()
+            .entry(format!("part{part}"))
+            .or_default()
+    }
+
+    /// Records `answer` as the correct one.
+    pub fn set_correct(&mut self, year: u16, day: u8, part: usize, answer: &str) {
+        self.record_mut(year, day, part).answer = Some(answer.to_owned());
+    }
+
+    /// Adds a rejected guess, replacing an earlier record of the same value.
+    pub fn add_wrong(&mut self, year: u16, day: u8, part: usize, guess: Guess) {
+        let wrong = &mut self.record_mut(year, day, part).wrong;
+        wrong.retain(|g| g.value != guess.value);
+        wrong.push(guess);
+    }
+
+    pub fn save(&self) -> Result<(), AnswersError> {
+        let contents = toml::to_string_pretty(&self.years).expect("answers always serialize");
+        std::fs::write(&self.path, format!("{HEADER}\n{contents}"))
+            .map_err(|err| AnswersError::Io(self.path.clone(), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(value: &str, hint: Option<Hint>) -> Guess {
+        Guess {
+            value: value.to_owned(),
+            hint,
+        }
+    }
+
+    #[test]
+    fn checks_against_the_answer() {
+        let record = Record {
+            answer: Some("42".to_owned()),
+            wrong: vec![],
+        };
+        assert_eq!(record.check("42"), Verdict::Correct);
+        assert_eq!(
+            record.check("41"),
+            Verdict::Wrong {
+                expected: "42".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_known_bad_guesses() {
+        let record = Record {
+            answer: None,
+            wrong: vec![
+                guess("100", Some(Hint::TooHigh)),
+                guess("10", Some(Hint::TooLow)),
+                guess("50", None),
+            ],
+        };
+        assert_eq!(
+            record.check("150"),
+            Verdict::Rejected(guess("100", Some(Hint::TooHigh)))
+        );
+        assert_eq!(
+            record.check("3"),
+            Verdict::Rejected(guess("10", Some(Hint::TooLow)))
+        );
+        assert_eq!(record.check("50"), Verdict::Rejected(guess("50", None)));
+        assert_eq!(record.check("51"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn round_trips() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let mut answers = Answers::load(&path).unwrap();
+        answers.set_correct(2025, 5, 1, "3");
+        answers.add_wrong(2025, 5, 2, guess("20", Some(Hint::TooHigh)));
+        answers.save().unwrap();
+
+        let loaded = Answers::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.check(2025, 5, 1, "3"), Verdict::Correct);
+        assert!(loaded.check(2025, 5, 2, "25").is_wrong());
+        assert_eq!(loaded.check(2025, 6, 1, "3"), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Downloads puzzle inputs from the Advent of Code website.
+///
+/// The session token is the value of the `session` cookie of a logged in
+/// browser, taken from `AOC_SESSION` or the first line of the file named by
+/// `AOC_SESSION_FILE` (`.aoc-session` at the top of the workspace by default).
+/// `AOC_BASE_URL` points the fetcher somewhere else, such
+/// as a local mock server.
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    Http(ureq::Error),
+    Status(u16),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Http(err) => write!(f, "{}", err),
+            FetchError::Status(status) => write!(f, "server responded with status {}", status),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl Fetcher {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Fetcher {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    pub fn from_env() -> Option<Self> {
+        let session = match std::env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => {
+                let file = std::env::var_os("AOC_SESSION_FILE")
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| {
+                        Path::new(env!("CARGO_MANIFEST_DIR")).join("../.aoc-session")
+                    });
+                let contents = std::fs::read_to_string(file).ok()?;
+                contents.lines().next()?.to_string()
+            }
+        };
+
+        let session = session.trim();
+        if session.is_empty() {
+            return None;
+        }
+
+        let base_url =
+            std::env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Some(Fetcher::new(base_url, session))
+    }
+
+    pub fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+
+        let mut response = ureq::get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", "github.com/CEbbinghaus/aoc")
+            .config()
+            .http_status_as_error(false)
+            .build()
+            .call()
+            .map_err(FetchError::Http)?;
+
+        let status = response.status().as_u16();
+        if status != 200 {
+            return Err(FetchError::Status(status));
+        }
+
+        response
+            .body_mut()
+            .read_to_string()
+            .map_err(FetchError::Http)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    #[test]
+    fn reports_bad_status() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let head = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect::<Vec<_>>();
+            write!(
+                stream,
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            )
+            .unwrap();
+            head[0].clone()
+        });
+
+        let fetcher = Fetcher::new(base_url, "secret");
+        assert!(matches!(
+            fetcher.fetch(2025, 13),
+            Err(FetchError::Status(404))
+        ));
+        assert!(
+            server
+                .join()
+                .unwrap()
+                .starts_with("GET /2025/day/13/input ")
+        );
+    }
+}
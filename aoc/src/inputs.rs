@@ -0,0 +1,214 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "fetch")]
+use crate::fetch::{FetchError, Fetcher};
+
+/// Where puzzle inputs live on disk.
+///
+/// Real inputs are cached as `<root>/<year>/day<N>.txt` and the worked
+/// examples from the puzzle text as `<root>/<year>/day<N>.example.txt`.
+/// The root defaults to `inputs/` at the top of the workspace and can be
+/// moved with the `AOC_INPUTS` environment variable.
+pub struct Inputs {
+    root: PathBuf,
+    #[cfg(feature = "fetch")]
+    fetcher: Option<Fetcher>,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The input is not cached and could not be downloaded either.
+    Missing(PathBuf),
+    Io(PathBuf, io::Error),
+    #[cfg(feature = "fetch")]
+    Fetch(FetchError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(
+                f,
+                "{} does not exist and no session token is configured to download it",
+                path.display()
+            ),
+            InputError::Io(path, err) => write!(f, "could not access {}: {}", path.display(), err),
+            #[cfg(feature = "fetch")]
+            InputError::Fetch(err) => write!(f, "could not download input: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl Inputs {
+    /// Inputs under `root` that are never downloaded.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Inputs {
+            root: root.into(),
+            #[cfg(feature = "fetch")]
+            fetcher: None,
+        }
+    }
+
+    /// Downloads missing inputs with `fetcher`.
+    #[cfg(feature = "fetch")]
+    pub fn with_fetcher(self, fetcher: Fetcher) -> Self {
+        Inputs {
+            fetcher: Some(fetcher),
+            ..self
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let inputs = match std::env::var_os("AOC_INPUTS") {
+            Some(root) => Inputs::new(root),
+            None => Inputs::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs")),
+        };
+
+        #[cfg(feature = "fetch")]
+        if let Some(fetcher) = Fetcher::from_env() {
+            return inputs.with_fetcher(fetcher);
+        }
+        inputs
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day{day}.txt"))
+    }
+
+    pub fn example_path(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day{day}.example.txt"))
+    }
+
+    /// Reads the example for a day. Examples are never downloaded, they have
+    /// to be pasted in from the puzzle text.
+    pub fn example(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let path = self.example_path(year, day);
+        if !path.exists() {
+            return Err(InputError::Missing(path));
+        }
+        read(&path)
+    }
+
+    /// Reads the real input for a day, downloading and caching it first if it
+    /// is not on disk yet. Once cached, an input is never downloaded again.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let path = self.input_path(year, day);
+        if path.exists() {
+            return read(&path);
+        }
+
+        #[cfg(feature = "fetch")]
+        if let Some(fetcher) = &self.fetcher {
+            let contents = fetcher.fetch(year, day).map_err(InputError::Fetch)?;
+            write(&path, &contents)?;
+            return Ok(contents);
+        }
+
+        Err(InputError::Missing(path))
+    }
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|err| InputError::Io(path.to_owned(), err))
+}
+
+#[cfg(feature = "fetch")]
+fn write(path: &Path, contents: &str) -> Result<(), InputError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| InputError::Io(parent.to_owned(), err))?;
+    }
+    std::fs::write(path, contents).map_err(|err| InputError::Io(path.to_owned(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-inputs-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        root
+    }
+
+    #[test]
+    fn reads_examples_without_downloading() {
+        let root = temp_root("examples");
+        let inputs = Inputs::new(&root);
+        assert!(matches!(
+            inputs.example(2025, 1),
+            Err(InputError::Missing(_))
+        ));
+        assert!(matches!(inputs.input(2025, 1), Err(InputError::Missing(_))));
+
+        std::fs::create_dir_all(root.join("2025")).unwrap();
+        std::fs::write(root.join("2025/day1.example.txt"), "L68\n").unwrap();
+        assert_eq!(inputs.example(2025, 1).unwrap(), "L68\n");
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn downloads_once_and_caches() {
+        use std::{
+            io::{BufRead, BufReader, Write},
+            net::TcpListener,
+            sync::{Arc, Mutex},
+        };
+
+        // Answers every request with the same input and keeps the request
+        // lines and headers it was sent.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::<Vec<String>>::new()));
+        let received = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let head = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                received.lock().unwrap().push(head);
+
+                let body = "R10\nL5\n";
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        let root = temp_root("fetch");
+        let inputs = Inputs::new(&root).with_fetcher(Fetcher::new(base_url, "secret"));
+
+        assert_eq!(inputs.input(2025, 3).unwrap(), "R10\nL5\n");
+        assert_eq!(
+            std::fs::read_to_string(root.join("2025/day3.txt")).unwrap(),
+            "R10\nL5\n"
+        );
+        assert_eq!(inputs.input(2025, 3).unwrap(), "R10\nL5\n");
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0][0].starts_with("GET /2025/day/3/input "));
+        assert!(
+            requests[0]
+                .iter()
+                .any(|header| header.eq_ignore_ascii_case("cookie: session=secret"))
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,4 @@
+pub mod answers;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod inputs;
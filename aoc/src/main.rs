@@ -0,0 +1,332 @@
+use std::{
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    answers::{Answers, Guess, Hint},
+    inputs::{self, Inputs},
+};
+use aoc_common::{Answer, OptionError, Options, ParseError, Solution};
+
+struct PartReport {
+    answer: String,
+    solved: bool,
+    elapsed: Duration,
+}
+
+/// Progress of a single run, reported as soon as each step finishes so that
+/// a panicking part 2 still shows the answer to part 1.
+enum Step {
+    Parsed(Duration),
+    Part(usize, PartReport),
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+fn part_report(answer: impl Answer, elapsed: Duration) -> PartReport {
+    PartReport {
+        answer: answer.to_string(),
+        solved: answer.is_solved(),
+        elapsed,
+    }
+}
+
+/// Why a run stopped before it got to the parts.
+enum Failure {
+    Options(OptionError),
+    Parse(ParseError),
+}
+
+fn execute<S: Solution + Default>(
+    contents: &str,
+    options: &Options,
+    report: &mut dyn FnMut(Step),
+) -> Result<(), Failure> {
+    let mut solution = S::default();
+    solution.configure(options).map_err(Failure::Options)?;
+
+    let (input, elapsed) = timed(|| solution.parse(contents));
+    let input = input.map_err(Failure::Parse)?;
+    report(Step::Parsed(elapsed));
+
+    let (answer, elapsed) = timed(|| solution.part1(&input));
+    report(Step::Part(1, part_report(answer, elapsed)));
+
+    let (answer, elapsed) = timed(|| solution.part2(&input));
+    report(Step::Part(2, part_report(answer, elapsed)));
+
+    solution.report(&input);
+
+    Ok(())
+}
+
+type Execute = fn(&str, &Options, &mut dyn FnMut(Step)) -> Result<(), Failure>;
+
+struct Day {
+    year: u16,
+    day: u8,
+    execute: Execute,
+}
+
+const DAYS: &[Day] = &[
+    Day {
+        year: 2015,
+        day: 1,
+        execute: execute::<aoc2015_day1::Day1>,
+    },
+    Day {
+        year: 2025,
+        day: 1,
+        execute: execute::<aoc2025_day1::Day1>,
+    },
+    Day {
+        year: 2025,
+        day: 2,
+        execute: execute::<aoc2025_day2::Day2>,
+    },
+    Day {
+        year: 2025,
+        day: 3,
+        execute: execute::<aoc2025_day3::Day3>,
+    },
+    Day {
+        year: 2025,
+        day: 4,
+        execute: execute::<aoc2025_day4::Day4>,
+    },
+    Day {
+        year: 2025,
+        day: 5,
+        execute: execute::<aoc2025_day5::Day5>,
+    },
+    Day {
+        year: 2025,
+        day: 6,
+        execute: execute::<aoc2025_day6::Day6>,
+    },
+    Day {
+        year: 2025,
+        day: 7,
+        execute: execute::<aoc2025_day7::Day7>,
+    },
+    Day {
+        year: 2025,
+        day: 8,
+        execute: execute::<aoc2025_day8::Day8>,
+    },
+    Day {
+        year: 2025,
+        day: 9,
+        execute: execute::<aoc2025_day9::Day9>,
+    },
+    Day {
+        year: 2025,
+        day: 10,
+        execute: execute::<aoc2025_day10::Day10>,
+    },
+    Day {
+        year: 2025,
+        day: 11,
+        execute: execute::<aoc2025_day11::Day11>,
+    },
+    Day {
+        year: 2025,
+        day: 12,
+        execute: execute::<aoc2025_day12::Day12>,
+    },
+];
+
+const USAGE: &str = "\
+usage: aoc <year> <day> [<file> | --example] [-o <key>=<value>]...
+       aoc answer <year> <day> <part> <answer>
+       aoc wrong <year> <day> <part> <answer> [too-high | too-low]";
+
+enum Source {
+    Input,
+    Example,
+    File(String),
+}
+
+enum Command {
+    Run(u16, u8, Source, Options),
+    /// Records the correct answer to a part.
+    Answer(u16, u8, usize, String),
+    /// Records a rejected answer to a part, with the hint the site gave.
+    Wrong(u16, u8, usize, Guess),
+}
+
+fn parse_args(args: &[String]) -> Option<Command> {
+    let (args, options) = Options::split_args(args)
+        .map_err(|err| eprintln!("{err}"))
+        .ok()?;
+    let part = |part: &str| part.parse().ok().filter(|part| matches!(part, 1 | 2));
+
+    let command = match args.as_slice() {
+        ["answer" | "wrong", ..] if !options.is_empty() => return None,
+        ["answer", year, day, p, answer] => Command::Answer(
+            year.parse().ok()?,
+            day.parse().ok()?,
+            part(p)?,
+            answer.to_string(),
+        ),
+        ["wrong", year, day, p, answer, hint @ ..] => {
+            let hint = match hint {
+                [] => None,
+                ["too-high"] => Some(Hint::TooHigh),
+                ["too-low"] => Some(Hint::TooLow),
+                _ => return None,
+            };
+            let guess = Guess {
+                value: answer.to_string(),
+                hint,
+            };
+            Command::Wrong(year.parse().ok()?, day.parse().ok()?, part(p)?, guess)
+        }
+        [year, day] => Command::Run(
+            year.parse().ok()?,
+            day.parse().ok()?,
+            Source::Input,
+            options,
+        ),
+        [year, day, "--example"] => Command::Run(
+            year.parse().ok()?,
+            day.parse().ok()?,
+            Source::Example,
+            options,
+        ),
+        [year, day, file] if !file.starts_with("-") => Command::Run(
+            year.parse().ok()?,
+            day.parse().ok()?,
+            Source::File(file.to_string()),
+            options,
+        ),
+        _ => return None,
+    };
+
+    Some(command)
+}
+
+fn record(update: impl FnOnce(&mut Answers)) -> ExitCode {
+    let result = Answers::from_env().and_then(|mut answers| {
+        update(&mut answers);
+        answers.save()?;
+        Ok(answers)
+    });
+
+    match result {
+        Ok(answers) => {
+            println!("Updated {}", answers.path().display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let (year, day, source, options) = match parse_args(&args) {
+        Some(Command::Run(year, day, source, options)) => (year, day, source, options),
+        Some(Command::Answer(year, day, part, answer)) => {
+            return record(|answers| answers.set_correct(year, day, part, &answer));
+        }
+        Some(Command::Wrong(year, day, part, guess)) => {
+            return record(|answers| answers.add_wrong(year, day, part, guess));
+        }
+        None => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let Some(solution) = DAYS.iter().find(|d| d.year == year && d.day == day) else {
+        eprintln!("no solution for {year} day {day}");
+        return ExitCode::FAILURE;
+    };
+
+    // Recorded answers belong to the real input with the default settings,
+    // examples, other files and variants are never checked against them.
+    let answers = match source {
+        Source::Input if options.is_empty() => match Answers::from_env() {
+            Ok(answers) => Some(answers),
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => None,
+    };
+
+    let inputs = Inputs::from_env();
+    let contents = match source {
+        Source::Input => {
+            println!("Reading input: {}", inputs.input_path(year, day).display());
+            inputs.input(year, day)
+        }
+        Source::Example => {
+            println!(
+                "Reading example: {}",
+                inputs.example_path(year, day).display()
+            );
+            inputs.example(year, day)
+        }
+        Source::File(file) => {
+            println!("Reading file: {}", file);
+            std::fs::read_to_string(&file).map_err(|err| inputs::InputError::Io(file.into(), err))
+        }
+    };
+
+    let contents = match contents {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut wrong = false;
+    let result = (solution.execute)(&contents, &options, &mut |step| match step {
+        Step::Parsed(elapsed) => println!("Parsed input in {:?}", elapsed),
+        Step::Part(part, report) if report.solved => {
+            print!(
+                "Solution {}: {} in {:?}",
+                part, report.answer, report.elapsed
+            );
+            match &answers {
+                Some(answers) => {
+                    let verdict = answers.check(year, day, part, &report.answer);
+                    wrong |= verdict.is_wrong();
+                    println!(" ({verdict})");
+                }
+                None => println!(),
+            }
+        }
+        Step::Part(part, report) => println!("Solution {}: {}", part, report.answer),
+    });
+
+    match result {
+        Ok(()) => {}
+        Err(Failure::Options(err)) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+        Err(Failure::Parse(err)) => {
+            eprintln!("{}", err.diagnostic(&contents));
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if wrong {
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
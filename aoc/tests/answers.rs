@@ -0,0 +1,71 @@
+//! Runs every day on its real input and checks both parts against the
+//! confirmed answers in `answers.toml`.
+//!
+//! Real inputs are personal and not committed, so these tests are ignored by
+//! default and skip days without a cached input or a recorded answer:
+//!
+//! ```sh
+//! cargo test -p aoc --test answers -- --ignored
+//! ```
+
+use aoc::{answers::Answers, inputs::Inputs};
+use aoc_common::Solution;
+
+/// Describes every part whose answer differs from the recorded one.
+fn check<S: Solution + Default>(year: u16, day: u8) -> Vec<String> {
+    let path = Inputs::from_env().input_path(year, day);
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        eprintln!(
+            "skipping {year} day {day}, {} does not exist",
+            path.display()
+        );
+        return Vec::new();
+    };
+    let answers = Answers::from_env().expect("answers.toml is readable");
+
+    let solution = S::default();
+    let input = solution
+        .parse(&contents)
+        .unwrap_or_else(|err| panic!("{year} day {day}: {err}"));
+    let parts = [
+        (1, solution.part1(&input).to_string()),
+        (2, solution.part2(&input).to_string()),
+    ];
+
+    parts
+        .into_iter()
+        .filter_map(|(part, answer)| {
+            let expected = answers.get(year, day, part)?.answer.as_ref()?;
+            (*expected != answer)
+                .then(|| format!("{year} day {day} part {part}: got {answer}, expected {expected}"))
+        })
+        .collect()
+}
+
+#[test]
+#[ignore = "needs the real inputs in inputs/"]
+fn real_answers_2015() {
+    let wrong = check::<aoc2015_day1::Day1>(2015, 1);
+    assert!(wrong.is_empty(), "{}", wrong.join("\n"));
+}
+
+#[test]
+#[ignore = "needs the real inputs in inputs/"]
+fn real_answers_2025() {
+    let wrong = [
+        check::<aoc2025_day1::Day1>(2025, 1),
+        check::<aoc2025_day2::Day2>(2025, 2),
+        check::<aoc2025_day3::Day3>(2025, 3),
+        check::<aoc2025_day4::Day4>(2025, 4),
+        check::<aoc2025_day5::Day5>(2025, 5),
+        check::<aoc2025_day6::Day6>(2025, 6),
+        check::<aoc2025_day7::Day7>(2025, 7),
+        check::<aoc2025_day8::Day8>(2025, 8),
+        check::<aoc2025_day9::Day9>(2025, 9),
+        check::<aoc2025_day10::Day10>(2025, 10),
+        check::<aoc2025_day11::Day11>(2025, 11),
+        check::<aoc2025_day12::Day12>(2025, 12),
+    ]
+    .concat();
+    assert!(wrong.is_empty(), "{}", wrong.join("\n"));
+}
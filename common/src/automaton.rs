@@ -0,0 +1,700 @@
+//! Life-like cellular automata on a [`Grid`]: every cell is alive or dead,
+//! and whether it is in the next generation only depends on how many of its
+//! neighbours are alive.
+//!
+//! The cells start out on a grid, whose edges can be dead, wrap around, or
+//! open onto an unbounded plane.
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{Grid, IntervalSet, ParseError};
+
+/// Which cells count as neighbours of a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// Every cell within the radius in both directions, diagonals included.
+    Moore(usize),
+    /// Every cell within the radius in Manhattan distance.
+    VonNeumann(usize),
+}
+
+impl Neighbourhood {
+    pub fn offsets(self) -> Vec<(isize, isize)> {
+        let (Neighbourhood::Moore(radius) | Neighbourhood::VonNeumann(radius)) = self;
+        let radius = radius as isize;
+
+        let mut offsets = Vec::new();
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let inside = match self {
+                    Neighbourhood::Moore(_) => true,
+                    Neighbourhood::VonNeumann(_) => dx.abs() + dy.abs() <= radius,
+                };
+                if inside && (dx, dy) != (0, 0) {
+                    offsets.push((dx, dy));
+                }
+            }
+        }
+        offsets
+    }
+}
+
+/// `moore`, `von-neumann`, or either with a radius like `moore:2`.
+impl FromStr for Neighbourhood {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, radius) = match s.split_once(':') {
+            Some((kind, radius)) => (
+                kind,
+                radius
+                    .parse()
+                    .map_err(|_| format!("invalid radius {radius:?}"))?,
+            ),
+            None => (s, 1),
+        };
+
+        match kind {
+            "moore" => Ok(Neighbourhood::Moore(radius)),
+            "von-neumann" => Ok(Neighbourhood::VonNeumann(radius)),
+            _ => Err(format!(
+                "unknown neighbourhood {kind:?}, expected moore or von-neumann"
+            )),
+        }
+    }
+}
+
+/// The neighbour counts for which a dead cell comes alive, and for which a
+/// live cell stays alive. Every other cell is dead in the next generation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub birth: IntervalSet<usize>,
+    pub survival: IntervalSet<usize>,
+}
+
+impl Rule {
+    pub fn next(&self, alive: bool, neighbours: usize) -> bool {
+        if alive {
+            self.survival.contains(neighbours)
+        } else {
+            self.birth.contains(neighbours)
+        }
+    }
+}
+
+/// The usual `B3/S23` notation, a `B` followed by the birth counts and an
+/// `S` followed by the survival counts, one digit each. Larger neighbourhoods
+/// need counts above 9, which are given as a list of counts and ranges
+/// instead, like `B3/S4-24` or `B3,12/S4-8,10`.
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let count = |count: &str| count.parse::<usize>().ok();
+        let counts = |part: &str, prefix: char| {
+            let counts = part.strip_prefix(prefix).ok_or_else(|| {
+                format!("expected {prefix} followed by neighbour counts, found {part:?}")
+            })?;
+
+            let ranges = if counts.contains([',', '-']) {
+                counts
+                    .split(',')
+                    .map(|range| match range.split_once('-') {
+                        Some((start, end)) => Some(count(start)?..=count(end)?),
+                        None => count(range).map(|n| n..=n),
+                    })
+                    .collect::<Option<Vec<_>>>()
+            } else {
+                counts
+                    .chars()
+                    .map(|c| c.to_digit(10).map(|n| n as usize..=n as usize))
+                    .collect()
+            };
+            ranges
+                .filter(|ranges| ranges.iter().all(|range| range.start() <= range.end()))
+                .map(IntervalSet::from_iter)
+                .ok_or_else(|| format!("invalid neighbour counts {counts:?}"))
+        };
+
+        let (birth, survival) = s
+            .split_once('/')
+            .ok_or_else(|| format!("invalid rule {s:?}, expected B<counts>/S<counts>"))?;
+        Ok(Rule {
+            birth: counts(birth, 'B')?,
+            survival: counts(survival, 'S')?,
+        })
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = |set: &IntervalSet<usize>| {
+            if set.values().all(|n| n <= 9) {
+                return set.values().map(|n| n.to_string()).collect::<String>();
+            }
+            let ranges = set
+                .iter()
+                .map(|range| {
+                    if range.start() == range.end() {
+                        range.start().to_string()
+                    } else {
+                        format!("{}-{}", range.start(), range.end())
+                    }
+                })
+                .collect::<Vec<_>>();
+            // A lone count like 12 would read as the digits 1 and 2.
+            match ranges.as_slice() {
+                [single] if !single.contains('-') => format!("{single}-{single}"),
+                _ => ranges.join(","),
+            }
+        };
+        write!(f, "B{}/S{}", counts(&self.birth), counts(&self.survival))
+    }
+}
+
+/// How a generation is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Update {
+    /// Every cell looks at the previous generation.
+    Synchronous,
+    /// Cells are updated one after another in reading order, and later cells
+    /// already see the changes of earlier ones.
+    Asynchronous,
+}
+
+impl FromStr for Update {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sync" => Ok(Update::Synchronous),
+            "async" => Ok(Update::Asynchronous),
+            _ => Err(format!("unknown update mode {s:?}, expected sync or async")),
+        }
+    }
+}
+
+/// The characters of live and dead cells in puzzle inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alphabet {
+    pub alive: char,
+    pub dead: char,
+}
+
+impl Alphabet {
+    pub fn parse(&self, input: &str) -> Result<Grid<bool>, ParseError> {
+        Grid::parse(input, |c| match c {
+            c if c == self.alive => Ok(true),
+            c if c == self.dead => Ok(false),
+            _ => Err(format!(
+                "invalid cell {c:?}, expected {:?} or {:?}",
+                self.alive, self.dead
+            )),
+        })
+    }
+
+    pub fn render(&self, cells: &Grid<bool>) -> String {
+        cells.render(|&alive| if alive { self.alive } else { self.dead })
+    }
+}
+
+/// What lies beyond the edges of the starting grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// Nothing, every cell outside the grid is dead for good.
+    Dead,
+    /// The grid wraps around, the right edge touches the left one and the
+    /// bottom edge touches the top one.
+    Torus,
+    /// The grid is a window onto an unbounded plane, and cells can come alive
+    /// anywhere next to live ones.
+    Infinite,
+}
+
+impl FromStr for Boundary {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dead" => Ok(Boundary::Dead),
+            "torus" => Ok(Boundary::Torus),
+            "infinite" => Ok(Boundary::Infinite),
+            _ => Err(format!(
+                "unknown boundary {s:?}, expected dead, torus or infinite"
+            )),
+        }
+    }
+}
+
+/// A complete description of an automaton, everything but the cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Automaton {
+    pub neighbourhood: Neighbourhood,
+    pub rule: Rule,
+    pub update: Update,
+    pub boundary: Boundary,
+}
+
+impl Automaton {
+    /// Starts a simulation of `cells`, with the top left cell at `(0, 0)`.
+    pub fn simulate(&self, cells: Grid<bool>) -> Simulation {
+        let offsets = self.neighbourhood.offsets();
+        let (width, height) = (cells.width(), cells.height());
+
+        let space: Box<dyn Space> = match self.boundary {
+            Boundary::Dead => Box::new(Bounded::new(cells, offsets, false)),
+            Boundary::Torus => Box::new(Bounded::new(cells, offsets, true)),
+            Boundary::Infinite => Box::new(Plane::new(&cells, offsets)),
+        };
+        let candidates = space.initial();
+
+        Simulation {
+            rule: self.rule.clone(),
+            update: self.update,
+            width,
+            height,
+            space,
+            candidates,
+        }
+    }
+}
+
+/// Where the cells of a simulation live, together with the live neighbour
+/// count of every cell and the cells already queued for the next generation.
+trait Space {
+    /// The cells that may change in the first generation.
+    fn initial(&self) -> Vec<(isize, isize)>;
+    fn alive(&self, position: (isize, isize)) -> bool;
+    fn count(&self, position: (isize, isize)) -> usize;
+    /// Flips a cell and updates the counts of its neighbours.
+    fn flip(&mut self, position: (isize, isize));
+    fn neighbours(&self, position: (isize, isize)) -> Vec<(isize, isize)>;
+    /// Marks a cell as queued, false if it already was.
+    fn queue(&mut self, position: (isize, isize)) -> bool;
+    fn dequeue(&mut self, position: (isize, isize));
+    /// Every live cell, in no particular order.
+    fn live(&self) -> Vec<(isize, isize)>;
+}
+
+/// The cells of a grid, with either dead or wrapping edges.
+struct Bounded {
+    cells: Grid<bool>,
+    counts: Grid<usize>,
+    queued: Grid<bool>,
+    offsets: Vec<(isize, isize)>,
+    wrap: bool,
+}
+
+impl Bounded {
+    fn new(cells: Grid<bool>, offsets: Vec<(isize, isize)>, wrap: bool) -> Self {
+        let counts = cells.map(|_| 0);
+        let queued = cells.map(|_| true);
+        let mut bounded = Bounded {
+            cells,
+            counts,
+            queued,
+            offsets,
+            wrap,
+        };
+
+        for (x, y) in bounded.cells.positions() {
+            let count = bounded
+                .neighbours((x as isize, y as isize))
+                .into_iter()
+                .filter(|&p| bounded.alive(p))
+                .count();
+            bounded.counts[(x, y)] = count;
+        }
+        bounded
+    }
+}
+
+fn index((x, y): (isize, isize)) -> (usize, usize) {
+    (x as usize, y as usize)
+}
+
+impl Space for Bounded {
+    fn initial(&self) -> Vec<(isize, isize)> {
+        self.cells
+            .positions()
+            .map(|(x, y)| (x as isize, y as isize))
+            .collect()
+    }
+
+    fn alive(&self, position: (isize, isize)) -> bool {
+        self.cells[index(position)]
+    }
+
+    fn count(&self, position: (isize, isize)) -> usize {
+        self.counts[index(position)]
+    }
+
+    fn flip(&mut self, position: (isize, isize)) {
+        let alive = !self.cells[index(position)];
+        self.cells[index(position)] = alive;
+
+        for neighbour in self.neighbours(position) {
+            if alive {
+                self.counts[index(neighbour)] += 1;
+            } else {
+                self.counts[index(neighbour)] -= 1;
+            }
+        }
+    }
+
+    fn neighbours(&self, position: (isize, isize)) -> Vec<(isize, isize)> {
+        let (x, y) = index(position);
+        let widen = |(x, y): (usize, usize)| (x as isize, y as isize);
+        if self.wrap {
+            self.cells
+                .neighbours_wrapping(x, y, &self.offsets)
+                .into_iter()
+                .map(widen)
+                .collect()
+        } else {
+            self.cells
+                .neighbours(x, y, &self.offsets)
+                .map(widen)
+                .collect()
+        }
+    }
+
+    fn queue(&mut self, position: (isize, isize)) -> bool {
+        !std::mem::replace(&mut self.queued[index(position)], true)
+    }
+
+    fn dequeue(&mut self, position: (isize, isize)) {
+        self.queued[index(position)] = false;
+    }
+
+    fn live(&self) -> Vec<(isize, isize)> {
+        self.cells
+            .iter()
+            .filter(|(_, alive)| **alive)
+            .map(|((x, y), _)| (x as isize, y as isize))
+            .collect()
+    }
+}
+
+/// An unbounded plane holding only the live cells, and counts only for the
+/// cells that have live neighbours.
+///
+/// Cells without live neighbours are never looked at, so rules with a birth
+/// count of zero don't fill the plane.
+struct Plane {
+    live: HashSet<(isize, isize)>,
+    counts: HashMap<(isize, isize), usize>,
+    queued: HashSet<(isize, isize)>,
+    offsets: Vec<(isize, isize)>,
+}
+
+impl Plane {
+    fn new(cells: &Grid<bool>, offsets: Vec<(isize, isize)>) -> Self {
+        let mut plane = Plane {
+            live: HashSet::new(),
+            counts: HashMap::new(),
+            queued: HashSet::new(),
+            offsets,
+        };
+        for ((x, y), &alive) in cells.iter() {
+            if alive {
+                plane.flip((x as isize, y as isize));
+            }
+        }
+        plane.queued = plane.initial().into_iter().collect();
+        plane
+    }
+}
+
+impl Space for Plane {
+    fn initial(&self) -> Vec<(isize, isize)> {
+        let mut cells = self
+            .live
+            .iter()
+            .chain(self.counts.keys())
+            .copied()
+            .collect::<Vec<_>>();
+        cells.sort_unstable();
+        cells.dedup();
+        cells
+    }
+
+    fn alive(&self, position: (isize, isize)) -> bool {
+        self.live.contains(&position)
+    }
+
+    fn count(&self, position: (isize, isize)) -> usize {
+        self.counts.get(&position).copied().unwrap_or(0)
+    }
+
+    fn flip(&mut self, position: (isize, isize)) {
+        let alive = !self.live.remove(&position);
+        if alive {
+            self.live.insert(position);
+        }
+
+        for neighbour in self.neighbours(position) {
+            let count = self.counts.entry(neighbour).or_default();
+            if alive {
+                *count += 1;
+            } else {
+                *count -= 1;
+            }
+            if *count == 0 {
+                self.counts.remove(&neighbour);
+            }
+        }
+    }
+
+    fn neighbours(&self, (x, y): (isize, isize)) -> Vec<(isize, isize)> {
+        self.offsets
+            .iter()
+            .map(|&(dx, dy)| (x + dx, y + dy))
+            .collect()
+    }
+
+    fn queue(&mut self, position: (isize, isize)) -> bool {
+        self.queued.insert(position)
+    }
+
+    fn dequeue(&mut self, position: (isize, isize)) {
+        self.queued.remove(&position);
+    }
+
+    fn live(&self) -> Vec<(isize, isize)> {
+        self.live.iter().copied().collect()
+    }
+}
+
+/// A running automaton, iterating over the cells that change in every
+/// generation until the cells stop changing.
+///
+/// Every cell keeps a count of its live neighbours, and only cells next to a
+/// change can change in the next generation. So a generation only looks at
+/// the neighbourhoods of the previous changes instead of the whole grid.
+pub struct Simulation {
+    rule: Rule,
+    update: Update,
+    /// The size of the starting grid.
+    width: usize,
+    height: usize,
+    space: Box<dyn Space>,
+    /// The cells that may change in the next generation, in reading order.
+    candidates: Vec<(isize, isize)>,
+}
+
+impl Simulation {
+    /// The cells in the area of the starting grid. On an infinite plane there
+    /// may be more live cells outside of it, see [`Simulation::live`].
+    pub fn cells(&self) -> Grid<bool> {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self.space.alive((x as isize, y as isize))
+        })
+    }
+
+    /// Every live cell, in reading order.
+    pub fn live(&self) -> Vec<(isize, isize)> {
+        let mut live = self.space.live();
+        live.sort_unstable_by_key(|&(x, y)| (y, x));
+        live
+    }
+
+    fn changes(&self, position: (isize, isize)) -> bool {
+        let alive = self.space.alive(position);
+        self.rule.next(alive, self.space.count(position)) != alive
+    }
+
+    /// Queues `position` and its neighbours for the next generation, except
+    /// the ones `current` takes into the generation that is still running.
+    fn requeue(
+        &mut self,
+        position: (isize, isize),
+        mut current: impl FnMut((isize, isize)) -> bool,
+    ) {
+        for around in self
+            .space
+            .neighbours(position)
+            .into_iter()
+            .chain([position])
+        {
+            if !current(around) && self.space.queue(around) {
+                self.candidates.push(around);
+            }
+        }
+    }
+}
+
+impl Iterator for Simulation {
+    type Item = Vec<(isize, isize)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut candidates = std::mem::take(&mut self.candidates);
+        candidates.sort_unstable_by_key(|&(x, y)| (y, x));
+        for &position in &candidates {
+            self.space.dequeue(position);
+        }
+
+        let mut changed = Vec::new();
+        match self.update {
+            Update::Synchronous => {
+                changed = candidates
+                    .into_iter()
+                    .filter(|&p| self.changes(p))
+                    .collect();
+                for &position in &changed {
+                    self.space.flip(position);
+                }
+                for &position in &changed {
+                    self.requeue(position, |_| false);
+                }
+            }
+            Update::Asynchronous => {
+                // Keyed by `(y, x)`, so the pass goes through them in reading
+                // order. A flip adds its neighbours further on to this pass,
+                // and the ones already behind it to the next generation.
+                let mut pass = candidates
+                    .into_iter()
+                    .map(|(x, y)| (y, x))
+                    .collect::<BTreeSet<_>>();
+                while let Some((y, x)) = pass.pop_first() {
+                    if !self.changes((x, y)) {
+                        continue;
+                    }
+                    self.space.flip((x, y));
+                    changed.push((x, y));
+                    self.requeue((x, y), |(nx, ny)| {
+                        let later = (ny, nx) > (y, x);
+                        if later {
+                            pass.insert((ny, nx));
+                        }
+                        later
+                    });
+                }
+            }
+        }
+
+        if changed.is_empty() {
+            None
+        } else {
+            Some(changed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIFE: Alphabet = Alphabet {
+        alive: '#',
+        dead: '.',
+    };
+
+    fn life() -> Automaton {
+        Automaton {
+            neighbourhood: Neighbourhood::Moore(1),
+            rule: "B3/S23".parse().unwrap(),
+            update: Update::Synchronous,
+            boundary: Boundary::Dead,
+        }
+    }
+
+    const GLIDER: &str = ".#....\n..#...\n###...\n......\n......\n......\n";
+
+    #[test]
+    fn blinker_oscillates() {
+        let cells = LIFE.parse(".....\n..#..\n..#..\n..#..\n.....\n").unwrap();
+        let mut simulation = life().simulate(cells.clone());
+
+        simulation.next();
+        assert_eq!(
+            LIFE.render(&simulation.cells()),
+            ".....\n.....\n.###.\n.....\n.....\n"
+        );
+        simulation.next();
+        assert_eq!(simulation.cells(), cells);
+    }
+
+    #[test]
+    fn stops_when_stable() {
+        let cells = LIFE.parse("....\n.##.\n.##.\n....\n").unwrap();
+        assert_eq!(life().simulate(cells).count(), 0);
+    }
+
+    #[test]
+    fn glider_wraps_around_a_torus() {
+        let cells = LIFE.parse(GLIDER).unwrap();
+        let automaton = Automaton {
+            boundary: Boundary::Torus,
+            ..life()
+        };
+
+        // A glider moves one cell diagonally every four generations.
+        let mut simulation = automaton.simulate(cells.clone());
+        simulation.by_ref().take(4 * 6).for_each(drop);
+        assert_eq!(simulation.cells(), cells);
+    }
+
+    #[test]
+    fn glider_leaves_the_grid_on_an_infinite_plane() {
+        let cells = LIFE.parse(GLIDER).unwrap();
+        let automaton = Automaton {
+            boundary: Boundary::Infinite,
+            ..life()
+        };
+
+        let mut simulation = automaton.simulate(cells);
+        simulation.by_ref().take(4 * 10).for_each(drop);
+        assert_eq!(
+            simulation.live(),
+            [(11, 10), (12, 11), (10, 12), (11, 12), (12, 12)]
+        );
+        assert!(simulation.cells().iter().all(|(_, &alive)| !alive));
+
+        // With dead edges it crashes into the corner and ends as a block.
+        let mut simulation = life().simulate(LIFE.parse(GLIDER).unwrap());
+        simulation.by_ref().for_each(drop);
+        assert_eq!(simulation.live(), [(4, 4), (5, 4), (4, 5), (5, 5)]);
+    }
+
+    #[test]
+    fn neighbourhoods() {
+        assert_eq!(Neighbourhood::Moore(1).offsets().len(), 8);
+        assert_eq!(Neighbourhood::Moore(2).offsets().len(), 24);
+        assert_eq!(
+            "von-neumann:2"
+                .parse::<Neighbourhood>()
+                .unwrap()
+                .offsets()
+                .len(),
+            12
+        );
+        assert_eq!("B36/S23".parse::<Rule>().unwrap().to_string(), "B36/S23");
+        assert_eq!("torus".parse::<Boundary>(), Ok(Boundary::Torus));
+    }
+
+    #[test]
+    fn counts_above_nine() {
+        let rule = "B3,12/S10-24".parse::<Rule>().unwrap();
+        assert_eq!(rule.to_string(), "B3,12/S10-24");
+        assert_eq!("B/S12-12".parse::<Rule>().unwrap().to_string(), "B/S12-12");
+        assert_eq!(rule.to_string().parse(), Ok(rule.clone()));
+        assert_eq!("B3/S2-3".parse::<Rule>().unwrap().to_string(), "B3/S23");
+        assert!("B/S5-4".parse::<Rule>().is_err());
+        assert!("B/S4-".parse::<Rule>().is_err());
+
+        // Within two cells, the corners of a full square have 8 neighbours,
+        // and every other cell at least 11.
+        let automaton = Automaton {
+            neighbourhood: "moore:2".parse().unwrap(),
+            rule,
+            ..life()
+        };
+        let wave = automaton.simulate(Grid::filled(5, 5, true)).next().unwrap();
+        assert_eq!(wave, [(0, 0), (4, 0), (0, 4), (4, 4)]);
+    }
+}
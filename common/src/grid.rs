@@ -0,0 +1,335 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::ParseError;
+
+/// The four orthogonal neighbours (von Neumann neighbourhood).
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// All eight surrounding neighbours (Moore neighbourhood).
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// Cells are addressed as `(x, y)` with `x` the column and `y` the row,
+/// starting in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {width}x{height} grid needs {} cells",
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses one cell per character and one row per line, ignoring trailing
+    /// newlines. Every row has to be as wide as the first one.
+    pub fn parse(
+        input: &str,
+        cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        Grid::parse_within(input, input, cell)
+    }
+
+    /// Like [`Grid::parse`] for a grid embedded in a larger input. `text` has
+    /// to be a slice of `input` so that errors point at the right line.
+    pub fn parse_within(
+        input: &str,
+        text: &str,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in text.trim_end_matches('\n').split('\n') {
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                let value = cell(c).map_err(|message| {
+                    ParseError::at(input, &line[i..i + c.len_utf8()], message)
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected a row of {width} cells, found {row_width}"),
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell together with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The positions at the given offsets from `(x, y)` that are inside the grid.
+    pub fn neighbours<'a>(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + use<'a, T> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let (nx, ny) = (x as isize + dx, y as isize + dy);
+            (nx >= 0 && ny >= 0 && nx < width && ny < height).then_some((nx as usize, ny as usize))
+        })
+    }
+
+    /// The positions at the given offsets from `(x, y)` on a grid that wraps
+    /// around at its edges, like a torus. On a grid too small for the offsets
+    /// they can wrap back onto `(x, y)` or onto each other, those only count
+    /// once and `(x, y)` not at all.
+    pub fn neighbours_wrapping(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &[(isize, isize)],
+    ) -> Vec<(usize, usize)> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        let mut neighbours = Vec::with_capacity(offsets.len());
+
+        for &(dx, dy) in offsets {
+            let (nx, ny) = (x as isize + dx, y as isize + dy);
+            let neighbour = (
+                nx.rem_euclid(width) as usize,
+                ny.rem_euclid(height) as usize,
+            );
+            if neighbour != (x, y) && !neighbours.contains(&neighbour) {
+                neighbours.push(neighbour);
+            }
+        }
+        neighbours
+    }
+
+    /// The up to four orthogonal neighbours of `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.neighbours(x, y, &ORTHOGONAL)
+    }
+
+    /// The up to eight neighbours of `(x, y)`, including diagonals.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.neighbours(x, y, &ADJACENT)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Builds a grid of the given size from its cells' positions.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    pub fn transposed(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    pub fn rotated(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flipped(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Renders the grid one line per row, turning every cell into a character.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            output.extend(row.iter().map(&mut cell));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({x}, {y}) is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside of the {width}x{height} grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Ok).unwrap()
+    }
+
+    #[test]
+    fn parses_rows() {
+        let grid = letters();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let error = Grid::parse("abc\nde\n", Ok).unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (2, "de"));
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = letters();
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotated().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.flipped().to_string(), "cba\nfed\n");
+        assert_eq!(grid.rotated().rotated().rotated().rotated(), grid);
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = letters();
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8(1, 0).count(), 5);
+    }
+
+    #[test]
+    fn neighbours_wrap_around() {
+        let grid = letters();
+        assert_eq!(
+            grid.neighbours_wrapping(0, 0, &ORTHOGONAL),
+            [(0, 1), (2, 0), (1, 0)]
+        );
+        assert_eq!(grid.neighbours_wrapping(1, 1, &ADJACENT).len(), 5);
+        assert!(
+            Grid::filled(1, 1, ())
+                .neighbours_wrapping(0, 0, &ADJACENT)
+                .is_empty()
+        );
+    }
+}
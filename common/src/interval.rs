@@ -0,0 +1,379 @@
+use std::ops::RangeInclusive;
+
+/// A discrete, totally ordered value that can be the endpoint of a range.
+pub trait Discrete: Copy + Ord {
+    /// The next value, or `None` at the top of the domain.
+    fn successor(self) -> Option<Self>;
+
+    /// The previous value, or `None` at the bottom of the domain.
+    fn predecessor(self) -> Option<Self>;
+
+    /// How many successors it takes to get from `self` to `to`, which must
+    /// not be smaller. Always fits, since no domain has more than 2^128 values.
+    fn steps(self, to: Self) -> u128;
+}
+
+macro_rules! impl_discrete {
+    ($($ty:ty),*) => {
+        $(impl Discrete for $ty {
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn predecessor(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn steps(self, to: Self) -> u128 {
+                // Exact modulo 2^128 even where the cast wraps a u128.
+                (to as i128).wrapping_sub(self as i128) as u128
+            }
+        })*
+    };
+}
+
+impl_discrete!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// A set of values stored as sorted, disjoint inclusive ranges.
+///
+/// Overlapping ranges are merged on insertion, so lookups are a binary search
+/// over the ranges and never look at more than one of them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// Adds `range` to the set, merging it with every range it overlaps.
+    /// Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // The first range that does not end before `start`, and the first one
+        // that starts after `end`; everything in between overlaps.
+        let first = self.ranges.partition_point(|r| *r.end() < start);
+        let last = self.ranges.partition_point(|r| *r.start() <= end);
+
+        if first < last {
+            start = start.min(*self.ranges[first].start());
+            end = end.max(*self.ranges[last - 1].end());
+        }
+        self.ranges.splice(first..last, [start..=end]);
+    }
+
+    /// Whether `value` lies in one of the ranges.
+    pub fn contains(&self, value: T) -> bool {
+        self.covering(value).is_some()
+    }
+
+    /// The range that `value` lies in.
+    pub fn covering(&self, value: T) -> Option<&RangeInclusive<T>> {
+        let index = self.ranges.partition_point(|r| *r.end() < value);
+        self.ranges.get(index).filter(|r| *r.start() <= value)
+    }
+
+    /// How far `value` is from the closest value in the set, zero if it is in
+    /// the set and `None` if the set is empty. Counted in steps like
+    /// [`IntervalSet::total_len`], as a distance may not fit into a `T`.
+    pub fn distance(&self, value: T) -> Option<u128> {
+        let index = self.ranges.partition_point(|r| *r.end() < value);
+        let before = index
+            .checked_sub(1)
+            .map(|i| self.ranges[i].end().steps(value));
+        let after = self
+            .ranges
+            .get(index)
+            .map(|r| value.steps((*r.start()).max(value)));
+
+        match (before, after) {
+            (Some(before), Some(after)) => Some(before.min(after)),
+            (before, after) => before.or(after),
+        }
+    }
+
+    /// The covering range of every value, in the order of `values`.
+    ///
+    /// Sorts the values and walks them alongside the ranges, so a long list of
+    /// values costs a sort rather than a binary search each.
+    pub fn covering_all(&self, values: &[T]) -> Vec<Option<&RangeInclusive<T>>> {
+        let mut order = (0..values.len()).collect::<Vec<_>>();
+        order.sort_unstable_by_key(|&i| values[i]);
+
+        let mut covering = vec![None; values.len()];
+        let mut ranges = self.ranges.iter().peekable();
+        for i in order {
+            while ranges.next_if(|r| *r.end() < values[i]).is_some() {}
+            covering[i] = ranges.peek().copied().filter(|r| *r.start() <= values[i]);
+        }
+        covering
+    }
+
+    /// The number of values covered by the set, which can be more than a `T`
+    /// holds. `None` only if the set is all 2^128 values of a 128-bit type.
+    pub fn total_len(&self) -> Option<u128> {
+        self.ranges.iter().try_fold(0u128, |total, r| {
+            total.checked_add(r.start().steps(*r.end()).checked_add(1)?)
+        })
+    }
+
+    /// Merges ranges that touch without overlapping, like `3..=5` and
+    /// `6..=8`. Insertion keeps them apart, as the boundary between them may
+    /// matter.
+    pub fn merge_adjacent(&mut self) {
+        let ranges = std::mem::take(&mut self.ranges);
+        for range in ranges {
+            if let Some(last) = self.ranges.last_mut()
+                && last.end().successor() == Some(*range.start())
+            {
+                *last = *last.start()..=*range.end();
+                continue;
+            }
+            self.ranges.push(range);
+        }
+    }
+
+    /// The number of disjoint ranges.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The disjoint ranges in ascending order.
+    pub fn iter(&self) -> std::slice::Iter<'_, RangeInclusive<T>> {
+        self.ranges.iter()
+    }
+
+    /// Every value in the set in ascending order.
+    pub fn values(&self) -> impl Iterator<Item = T> + '_ {
+        self.ranges.iter().flat_map(|r| {
+            let (start, end) = (*r.start(), *r.end());
+            std::iter::successors(
+                Some(start),
+                move |&v| if v < end { v.successor() } else { None },
+            )
+        })
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter().cloned());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let start = *x.start().max(y.start());
+            let end = *x.end().min(y.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+
+            // Whichever range ends first can not overlap anything further on.
+            if x.end() < y.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let (mut start, end) = (*range.start(), *range.end());
+
+            // Skip the ranges that end before this one starts.
+            while others.next_if(|r| *r.end() < start).is_some() {}
+
+            let mut remaining = true;
+            for cut in others.clone() {
+                if *cut.start() > end {
+                    break;
+                }
+                if let Some(before) = cut.start().predecessor()
+                    && start <= before
+                {
+                    ranges.push(start..=before);
+                }
+                match cut.end().successor() {
+                    Some(after) if after <= end => start = after,
+                    _ => {
+                        remaining = false;
+                        break;
+                    }
+                }
+            }
+
+            if remaining {
+                ranges.push(start..=end);
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Discrete> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    /// Sorts the new ranges first and merges them in a single pass, rather
+    /// than inserting them one by one.
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.extend(iter.into_iter().filter(|r| r.start() <= r.end()));
+        ranges.sort_by_key(|r| *r.start());
+
+        for range in ranges {
+            if let Some(last) = self.ranges.last_mut()
+                && *last.end() >= *range.start()
+            {
+                *last = *last.start()..=*last.end().max(range.end());
+                continue;
+            }
+            self.ranges.push(range);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a RangeInclusive<T>;
+    type IntoIter = std::slice::Iter<'a, RangeInclusive<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<u32>]) -> IntervalSet<u32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn merges_overlapping_ranges() {
+        let mut ranges = set(&[3..=5, 10..=14, 16..=20, 12..=18]);
+        assert_eq!(ranges.iter().cloned().collect::<Vec<_>>(), [3..=5, 10..=20]);
+
+        ranges.insert(0..=3);
+        ranges.insert(7..=7);
+        assert_eq!(
+            ranges.iter().cloned().collect::<Vec<_>>(),
+            [0..=5, 7..=7, 10..=20]
+        );
+        assert_eq!(ranges.total_len(), Some(6 + 1 + 11));
+
+        ranges.insert(6..=6);
+        assert_eq!(ranges.len(), 4);
+        ranges.merge_adjacent();
+        assert_eq!(ranges.iter().cloned().collect::<Vec<_>>(), [0..=7, 10..=20]);
+    }
+
+    #[test]
+    fn lengths_do_not_overflow() {
+        assert_eq!(set(&[0..=u32::MAX]).total_len(), Some(1 << 32));
+        assert_eq!(
+            IntervalSet::from_iter([i8::MIN..=-1, 0..=i8::MAX]).total_len(),
+            Some(256)
+        );
+        assert_eq!(
+            IntervalSet::from_iter([u128::MAX - 1..=u128::MAX]).total_len(),
+            Some(2)
+        );
+        assert_eq!(
+            IntervalSet::from_iter([1..=u128::MAX]).total_len(),
+            Some(u128::MAX)
+        );
+        assert_eq!(
+            IntervalSet::from_iter([i128::MIN..=i128::MAX]).total_len(),
+            None
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let ranges = set(&[0..=0, 3..=5, 10..=14]);
+        let found = (0..16).filter(|&v| ranges.contains(v)).collect::<Vec<_>>();
+        assert_eq!(found, [0, 3, 4, 5, 10, 11, 12, 13, 14]);
+    }
+
+    #[test]
+    fn queries() {
+        let ranges = set(&[3..=5, 10..=14, 20..=20]);
+        assert_eq!(ranges.covering(12), Some(&(10..=14)));
+        assert_eq!(ranges.covering(7), None);
+
+        let distances = (0..24)
+            .map(|v| ranges.distance(v).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            distances,
+            [
+                3, 2, 1, 0, 0, 0, 1, 2, 2, 1, 0, 0, 0, 0, 0, 1, 2, 3, 2, 1, 0, 1, 2, 3
+            ]
+        );
+        assert_eq!(set(&[]).distance(1), None);
+
+        let signed = IntervalSet::from_iter([-128_i8..=-128]);
+        assert_eq!(signed.distance(127), Some(255));
+        assert_eq!(
+            IntervalSet::from_iter([i8::MAX..=i8::MAX]).distance(i8::MIN),
+            Some(255)
+        );
+
+        let values = [21, 4, 0, 14, 9, 3, 20, 4];
+        let covering = values
+            .iter()
+            .map(|&v| ranges.covering(v))
+            .collect::<Vec<_>>();
+        assert_eq!(ranges.covering_all(&values), covering);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..=10, 20..=30]);
+        let b = set(&[5..=25, 30..=u32::MAX]);
+
+        assert_eq!(a.union(&b), set(&[0..=u32::MAX]));
+        assert_eq!(a.intersection(&b), set(&[5..=10, 20..=25, 30..=30]));
+        assert_eq!(a.difference(&b), set(&[0..=4, 26..=29]));
+        assert_eq!(b.difference(&a), set(&[11..=19, 31..=u32::MAX]));
+        assert_eq!(set(&[0..=3]).values().collect::<Vec<_>>(), [0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt::{self, Display};
+
+pub mod automaton;
+pub mod grid;
+pub mod interval;
+pub mod options;
+pub mod parse;
+
+pub use grid::Grid;
+pub use interval::IntervalSet;
+pub use options::{OptionError, Options};
+pub use parse::ParseError;
+
+/// A single day's puzzle: a typed parse step followed by the two parts.
+///
+/// Both parts receive the same parsed input, so the (often expensive) parse
+/// is only done once and can be timed separately from the actual solutions.
+pub trait Solution {
+    type Input<'a>;
+    type Part1: Answer;
+    type Part2: Answer;
+
+    /// Applies the options given on the command line. Days without any
+    /// settings reject every option.
+    fn configure(&mut self, options: &Options) -> Result<(), OptionError> {
+        options.only(&[])
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError>;
+    fn part1(&self, input: &Self::Input<'_>) -> Self::Part1;
+    fn part2(&self, input: &Self::Input<'_>) -> Self::Part2;
+
+    /// Shows whatever the options asked for beyond the answers, like listings
+    /// or animations. Runs after both parts, so it never counts towards their
+    /// times.
+    fn report(&self, _input: &Self::Input<'_>) {}
+}
+
+/// Anything a part can return. Every answer is displayable, and a part that
+/// has not been solved yet says so by returning [`Unsolved`].
+pub trait Answer: Display {
+    fn is_solved(&self) -> bool {
+        true
+    }
+}
+
+macro_rules! impl_answer {
+    ($($ty:ty),*) => {
+        $(impl Answer for $ty {})*
+    };
+}
+
+impl_answer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String, &str
+);
+
+/// The answer of a part that has no implementation (yet).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unsolved")
+    }
+}
+
+impl Answer for Unsolved {
+    fn is_solved(&self) -> bool {
+        false
+    }
+}
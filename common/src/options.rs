@@ -0,0 +1,167 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Named settings for a day, given on the command line as `-o key=value`.
+///
+/// They cover puzzle variants, e.g. a different dial size, without touching
+/// the code. Days read what they understand in [`Solution::configure`] and
+/// reject everything else.
+///
+/// [`Solution::configure`]: crate::Solution::configure
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    values: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptionError {
+    /// Not of the form `key=value`.
+    Malformed(String),
+    Unknown(String),
+    Invalid {
+        key: String,
+        value: String,
+        expected: String,
+    },
+}
+
+impl Display for OptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionError::Malformed(option) => {
+                write!(f, "invalid option {option:?}, expected <key>=<value>")
+            }
+            OptionError::Unknown(key) => write!(f, "unknown option {key:?}"),
+            OptionError::Invalid {
+                key,
+                value,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "invalid value {value:?} for option {key:?}, expected {expected}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for OptionError {}
+
+impl OptionError {
+    pub fn invalid(key: &str, value: impl Display, expected: impl Into<String>) -> Self {
+        OptionError::Invalid {
+            key: key.to_owned(),
+            value: value.to_string(),
+            expected: expected.into(),
+        }
+    }
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Options::default()
+    }
+
+    /// Adds a `key=value` option, replacing an earlier value for the key.
+    pub fn insert(&mut self, option: &str) -> Result<(), OptionError> {
+        let (key, value) = option
+            .split_once('=')
+            .filter(|(key, _)| !key.is_empty())
+            .ok_or_else(|| OptionError::Malformed(option.to_owned()))?;
+        self.values.insert(key.to_owned(), value.to_owned());
+        Ok(())
+    }
+
+    /// Splits command line arguments into the `-o key=value` options and
+    /// everything else, which keeps its order.
+    pub fn split_args(args: &[String]) -> Result<(Vec<&str>, Options), OptionError> {
+        let mut rest = Vec::new();
+        let mut options = Options::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "-o" {
+                let option = args
+                    .next()
+                    .ok_or_else(|| OptionError::Malformed(arg.clone()))?;
+                options.insert(option)?;
+            } else {
+                rest.push(arg.as_str());
+            }
+        }
+
+        Ok((rest, options))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The value of `key` parsed as a `T`, `None` if it was not given.
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>, OptionError> {
+        let Some(value) = self.values.get(key) else {
+            return Ok(None);
+        };
+
+        value.parse().map(Some).map_err(|_| {
+            OptionError::invalid(key, value, format!("a {}", std::any::type_name::<T>()))
+        })
+    }
+
+    /// Fails on the first option that is not one of `known`.
+    pub fn only(&self, known: &[&str]) -> Result<(), OptionError> {
+        match self
+            .values
+            .keys()
+            .find(|key| !known.contains(&key.as_str()))
+        {
+            Some(key) => Err(OptionError::Unknown(key.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_options() {
+        let mut options = Options::new();
+        options.insert("size=10").unwrap();
+        options.insert("start=x").unwrap();
+
+        assert_eq!(options.get::<i64>("size"), Ok(Some(10)));
+        assert_eq!(options.get::<i64>("other"), Ok(None));
+        assert!(matches!(
+            options.get::<i64>("start"),
+            Err(OptionError::Invalid { .. })
+        ));
+        assert_eq!(
+            options.only(&["size"]),
+            Err(OptionError::Unknown("start".to_owned()))
+        );
+        assert_eq!(
+            options.insert("=1"),
+            Err(OptionError::Malformed("=1".to_owned()))
+        );
+    }
+
+    #[test]
+    fn splits_args() {
+        let args = ["2025", "-o", "size=10", "1", "-o", "start=5"].map(String::from);
+        let (rest, options) = Options::split_args(&args).unwrap();
+        assert_eq!(rest, ["2025", "1"]);
+        assert_eq!(options.get::<i64>("start"), Ok(Some(5)));
+
+        assert_eq!(
+            Options::split_args(&args[..2]),
+            Err(OptionError::Malformed("-o".to_owned()))
+        );
+        assert!(Options::split_args(&["-o".to_owned(), "size".to_owned()]).is_err());
+    }
+}
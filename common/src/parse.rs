@@ -0,0 +1,119 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// An error in a puzzle input, pointing at the offending text.
+///
+/// Lines and columns are 1-based and columns count characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error for `span`, which must be a slice of `input`. The line
+    /// and column are worked out from where the slice sits in the input, so
+    /// parsers can simply hand over whatever `&str` they choked on.
+    pub fn at(input: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = (span.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(offset <= input.len(), "span is not part of the input");
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            span,
+            message,
+        )
+    }
+
+    /// Renders the error together with the offending line of `input` and a
+    /// row of carets underneath the offending text.
+    pub fn diagnostic(&self, input: &str) -> String {
+        let Some(line) = input.lines().nth(self.line - 1) else {
+            return format!("error: {self}");
+        };
+
+        let gutter = self.line.to_string().len();
+        let width = self
+            .text
+            .lines()
+            .next()
+            .map_or(0, |text| text.chars().count())
+            .max(1);
+
+        format!(
+            "error: {message}\n{pad} --> line {line_number}, column {column}\n{pad} |\n{line_number} | {line}\n{pad} | {indent}{carets}",
+            message = self.message,
+            pad = " ".repeat(gutter),
+            line_number = self.line,
+            column = self.column,
+            indent = " ".repeat(self.column - 1),
+            carets = "^".repeat(width),
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}: {:?}",
+            self.message, self.line, self.column, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `span`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, span: &str) -> Result<T, ParseError> {
+    span.parse()
+        .map_err(|_| ParseError::at(input, span, "invalid number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_span_in_input() {
+        let input = "R10\nL5\nX7\n";
+        let error = ParseError::at(input, &input[7..8], "invalid direction");
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 1);
+        assert_eq!(error.text, "X");
+    }
+
+    #[test]
+    fn renders_carets_under_the_span() {
+        let input = "1-3\n4-x6\n";
+        let error = number::<u32>(input, &input[6..8]).unwrap_err();
+
+        assert_eq!(
+            error.diagnostic(input),
+            "error: invalid number\n  --> line 2, column 3\n  |\n2 | 4-x6\n  |   ^^"
+        );
+    }
+}